@@ -0,0 +1,106 @@
+const ESC: char = '\x1b';
+
+/// Maximum number of numeric parameters kept for a control sequence. Further parameters are ignored.
+const MAX_PARAMS: usize = 2;
+
+/// An action decoded from the printed text
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AnsiAction {
+    /// Print a character
+    Print(char),
+    /// Move the cursor to the start of the next row (`\n`)
+    NewLine,
+    /// Move the cursor to the start of the current row (`\r`)
+    CarriageReturn,
+    /// Move the cursor to the zero based row and column (`ESC[r;cH` or `ESC[r;cf`)
+    CursorPosition { row: u8, col: u8 },
+    /// Erase part of the screen (`ESC[nJ`): 0 = cursor to end, 1 = start to cursor, 2 = whole screen
+    EraseDisplay(u8),
+    /// Erase part of the row (`ESC[nK`): 0 = cursor to end, 1 = start to cursor, 2 = whole row
+    EraseLine(u8),
+    /// The character was consumed by an escape sequence
+    None,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Ground,
+    Escape,
+    ControlSequence,
+}
+
+/// Incremental parser state. The state is kept between calls so escape sequences may be split across several
+/// `print()` calls, as happens with the `write!` macro.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AnsiParser {
+    state: State,
+    params: [u8; MAX_PARAMS],
+    param_count: usize,
+}
+
+impl AnsiParser {
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: State::Ground,
+            params: [0; MAX_PARAMS],
+            param_count: 0,
+        }
+    }
+
+    /// Feed the next character to the parser
+    pub(crate) fn feed(&mut self, c: char) -> AnsiAction {
+        match self.state {
+            State::Ground => match c {
+                ESC => {
+                    self.state = State::Escape;
+                    AnsiAction::None
+                }
+                '\n' => AnsiAction::NewLine,
+                '\r' => AnsiAction::CarriageReturn,
+                _ => AnsiAction::Print(c),
+            },
+            State::Escape => {
+                if c == '[' {
+                    self.state = State::ControlSequence;
+                    self.params = [0; MAX_PARAMS];
+                    self.param_count = 0;
+                } else {
+                    // unsupported escape sequence, drop it
+                    self.state = State::Ground;
+                }
+                AnsiAction::None
+            }
+            State::ControlSequence => match c {
+                '0'..='9' => {
+                    if self.param_count == 0 {
+                        self.param_count = 1;
+                    }
+                    if self.param_count <= MAX_PARAMS {
+                        let param = &mut self.params[self.param_count - 1];
+                        *param = param.saturating_mul(10).saturating_add(c as u8 - b'0');
+                    }
+                    AnsiAction::None
+                }
+                ';' => {
+                    self.param_count = self.param_count.max(1) + 1;
+                    AnsiAction::None
+                }
+                '\x20'..='\x3f' => AnsiAction::None,
+                _ => {
+                    // final byte ends the sequence
+                    self.state = State::Ground;
+                    match c {
+                        'H' | 'f' => AnsiAction::CursorPosition {
+                            // positions are one based, with 0 treated as 1
+                            row: self.params[0].max(1) - 1,
+                            col: self.params[1].max(1) - 1,
+                        },
+                        'J' => AnsiAction::EraseDisplay(self.params[0]),
+                        'K' => AnsiAction::EraseLine(self.params[0]),
+                        _ => AnsiAction::None,
+                    }
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Number of full screen refreshes timed by `LcdBackpack::benchmark()`
+const BENCHMARK_FRAMES: u32 = 4;
+
+/// The throughput measured by `LcdBackpack::benchmark()`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BenchmarkResult {
+    /// Characters written to the display per second
+    pub chars_per_second: u32,
+    /// Time to rewrite every character on the display, including the cursor moves to each row, in microseconds
+    pub full_screen_refresh_us: u32,
+}
+
+impl<I2C, I2C_ERR, D> LcdBackpack<I2C, D>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    /// Measure how fast the display can be written with the current I2C bus speed and timing profile. A delay object
+    /// cannot measure time, so `now_us` must return a free running microsecond count, such as from a hardware timer;
+    /// it may wrap around. The screen is rewritten several times and the results are then shown on the display, with
+    /// the characters per second on the first row and the full screen refresh time on the second row if there is one.
+    pub fn benchmark(
+        &mut self,
+        mut now_us: impl FnMut() -> u32,
+    ) -> Result<BenchmarkResult, Error<I2C_ERR>> {
+        let rows = self.rows();
+        let cols = self.cols() as u32;
+        self.clear()?;
+
+        let start = now_us();
+        for frame in 0..BENCHMARK_FRAMES {
+            // alternate the characters so every write changes the display
+            let c = if frame % 2 == 0 { '#' } else { '-' };
+            for row in 0..rows {
+                self.set_cursor(0, row)?;
+                for _ in 0..cols {
+                    self.write_data(c as u8)?;
+                }
+            }
+        }
+        let elapsed_us = now_us().wrapping_sub(start).max(1);
+
+        let chars = BENCHMARK_FRAMES * rows as u32 * cols;
+        let result = BenchmarkResult {
+            chars_per_second: (chars as u64 * 1_000_000 / elapsed_us as u64) as u32,
+            full_screen_refresh_us: elapsed_us / BENCHMARK_FRAMES,
+        };
+
+        self.clear()?;
+        self.print_u32(result.chars_per_second)?.print(" ch/s")?;
+        if rows > 1 {
+            self.set_cursor(0, 1)?;
+        } else {
+            self.print(" ")?;
+        }
+        self.print_u32(result.full_screen_refresh_us)?
+            .print(" us")?;
+        Ok(result)
+    }
+}
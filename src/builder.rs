@@ -0,0 +1,135 @@
+use crate::{
+    Error, LcdBackpack, LcdDisplayType, PinMapping, TimingProfile, DEFAULT_I2C_ADDRESS,
+    LCD_FLAG_BLINKON, LCD_FLAG_CURSORON, LCD_FLAG_ENTRYLEFT, LCD_FLAG_ENTRYSHIFTINCREMENT,
+};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Builder for an LCD backpack. Collects the display configuration and initial display state, then creates and
+/// initializes the LCD backpack in one `build()` call.
+///
+/// ```rust,ignore
+/// let mut lcd = LcdBackpackBuilder::new(LcdDisplayType::Lcd20x4)
+///     .address(0x21)
+///     .show_cursor(true)
+///     .blink_cursor(true)
+///     .build(i2c, delay)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LcdBackpackBuilder {
+    lcd_type: LcdDisplayType,
+    address: u8,
+    pins: PinMapping,
+    timing: TimingProfile,
+    show_cursor: bool,
+    blink_cursor: bool,
+    backlight: bool,
+    left_to_right: bool,
+    autoscroll: bool,
+}
+
+impl LcdBackpackBuilder {
+    /// Create a new builder for the specified display type. The defaults match `LcdBackpack::new()`: the I2C address
+    /// is 0x20, the Adafruit backpack pin mapping is used, the backlight is on, the cursor is hidden and not blinking,
+    /// and text flows left to right without auto scroll.
+    pub fn new(lcd_type: LcdDisplayType) -> Self {
+        Self {
+            lcd_type,
+            address: DEFAULT_I2C_ADDRESS,
+            pins: PinMapping::default(),
+            timing: TimingProfile::default(),
+            show_cursor: false,
+            blink_cursor: false,
+            backlight: true,
+            left_to_right: true,
+            autoscroll: false,
+        }
+    }
+
+    /// Set the I2C address of the backpack
+    pub fn address(mut self, address: u8) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Set the MCP23008 pins the LCD is wired to
+    pub fn pin_mapping(mut self, pins: PinMapping) -> Self {
+        self.pins = pins;
+        self
+    }
+
+    /// Set the delays used when driving the LCD
+    pub fn timing(mut self, timing: TimingProfile) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Set the initial cursor visibility
+    pub fn show_cursor(mut self, show_cursor: bool) -> Self {
+        self.show_cursor = show_cursor;
+        self
+    }
+
+    /// Set the initial cursor blinking
+    pub fn blink_cursor(mut self, blink_cursor: bool) -> Self {
+        self.blink_cursor = blink_cursor;
+        self
+    }
+
+    /// Set the initial backlight state
+    pub fn backlight(mut self, backlight: bool) -> Self {
+        self.backlight = backlight;
+        self
+    }
+
+    /// Set the initial text flow direction to left to right
+    pub fn left_to_right(mut self) -> Self {
+        self.left_to_right = true;
+        self
+    }
+
+    /// Set the initial text flow direction to right to left
+    pub fn right_to_left(mut self) -> Self {
+        self.left_to_right = false;
+        self
+    }
+
+    /// Set the initial auto scroll mode
+    pub fn autoscroll(mut self, autoscroll: bool) -> Self {
+        self.autoscroll = autoscroll;
+        self
+    }
+
+    /// Create the LCD backpack and initialize the LCD with the collected configuration. Returns an error if the
+    /// backpack does not respond or the initialization fails.
+    pub fn build<I2C, I2C_ERR, D>(
+        self,
+        i2c: I2C,
+        delay: D,
+    ) -> Result<LcdBackpack<I2C, D>, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let mut lcd = LcdBackpack::try_new_with_address(self.lcd_type, i2c, delay, self.address)?;
+        lcd.pins = self.pins;
+        lcd.timing = self.timing;
+        lcd.backlight = self.backlight;
+        if self.show_cursor {
+            lcd.display_control |= LCD_FLAG_CURSORON;
+        }
+        if self.blink_cursor {
+            lcd.display_control |= LCD_FLAG_BLINKON;
+        }
+        if !self.left_to_right {
+            lcd.display_mode &= !LCD_FLAG_ENTRYLEFT;
+        }
+        if self.autoscroll {
+            lcd.display_mode |= LCD_FLAG_ENTRYSHIFTINCREMENT;
+        }
+        lcd.init()?;
+        Ok(lcd)
+    }
+}
@@ -0,0 +1,360 @@
+/// Maps characters to the character codes of the LCD's character ROM. `print()` and the `core::fmt::Write`
+/// implementation consult the mapper set with `LcdBackpack::set_character_mapper()` before sending each character, so
+/// the display's ROM mapping or custom substitutions can be supplied without changing the driver.
+///
+/// Any `Fn(char) -> Option<u8>` is a character mapper, so a plain function can be used:
+///
+/// ```rust,ignore
+/// fn map_arrows(c: char) -> Option<u8> {
+///     match c {
+///         '→' => Some(0x7E),
+///         '←' => Some(0x7F),
+///         _ if c.is_ascii() => Some(c as u8),
+///         _ => None,
+///     }
+/// }
+///
+/// lcd.set_character_mapper(Some(&map_arrows));
+/// ```
+pub trait CharacterMapper {
+    /// Returns the character code for `c`, or `None` if the display has no glyph for it
+    fn map(&self, c: char) -> Option<u8>;
+}
+
+impl<F> CharacterMapper for F
+where
+    F: Fn(char) -> Option<u8>,
+{
+    fn map(&self, c: char) -> Option<u8> {
+        self(c)
+    }
+}
+
+/// Character mapper for HD44780 displays with the A00 (Japanese) character ROM, the most common variant. Maps ASCII
+/// (except `\` and `~`, which the ROM replaces with `¥` and `→`), half-width katakana, and the Greek letters and symbols
+/// in the upper half of the ROM. `°` is mapped to the katakana semi-voiced mark, which is commonly used as a degree
+/// sign on these displays.
+#[cfg(feature = "charset-a00")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RomA00Mapper;
+
+#[cfg(feature = "charset-a00")]
+impl CharacterMapper for RomA00Mapper {
+    fn map(&self, c: char) -> Option<u8> {
+        match c {
+            '\\' | '~' => None,
+            ' '..='}' => Some(c as u8),
+            '¥' => Some(0x5C),
+            '→' => Some(0x7E),
+            '←' => Some(0x7F),
+            // half-width katakana and punctuation are in the same order as the ROM
+            '\u{FF61}'..='\u{FF9F}' => Some((c as u32 - 0xFF61) as u8 + 0xA1),
+            '°' => Some(0xDF),
+            'α' => Some(0xE0),
+            'ä' => Some(0xE1),
+            'β' | 'ß' => Some(0xE2),
+            'ε' => Some(0xE3),
+            'μ' | 'µ' => Some(0xE4),
+            'σ' => Some(0xE5),
+            'ρ' => Some(0xE6),
+            '√' => Some(0xE8),
+            '¢' => Some(0xEC),
+            '£' => Some(0xED),
+            'ñ' => Some(0xEE),
+            'ö' => Some(0xEF),
+            'θ' => Some(0xF2),
+            '∞' => Some(0xF3),
+            'Ω' => Some(0xF4),
+            'ü' => Some(0xF5),
+            'Σ' => Some(0xF6),
+            'π' => Some(0xF7),
+            '千' => Some(0xFA),
+            '万' => Some(0xFB),
+            '円' => Some(0xFC),
+            '÷' => Some(0xFD),
+            '█' => Some(0xFF),
+            _ => None,
+        }
+    }
+}
+
+/// Character mapper for HD44780 displays with the A02 (European) character ROM. Maps ASCII, the Latin-1 letters
+/// `À`-`ÿ`, and the arrows, quotes, Greek letters, Cyrillic letters and symbols found elsewhere in the ROM.
+#[cfg(feature = "charset-a02")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RomA02Mapper;
+
+#[cfg(feature = "charset-a02")]
+impl CharacterMapper for RomA02Mapper {
+    fn map(&self, c: char) -> Option<u8> {
+        match c {
+            ' '..='~' => Some(c as u8),
+            // the upper quarter of the ROM matches Latin-1
+            'À'..='ÿ' => Some(c as u8),
+            '▶' | '►' => Some(0x10),
+            '◀' | '◄' => Some(0x11),
+            '“' => Some(0x12),
+            '”' => Some(0x13),
+            '●' => Some(0x16),
+            '↵' => Some(0x17),
+            '↑' => Some(0x18),
+            '↓' => Some(0x19),
+            '→' => Some(0x1A),
+            '←' => Some(0x1B),
+            '≤' => Some(0x1C),
+            '≥' => Some(0x1D),
+            '▲' => Some(0x1E),
+            '▼' => Some(0x1F),
+            '⌂' => Some(0x7F),
+            'Б' => Some(0x80),
+            'Д' => Some(0x81),
+            'Ж' => Some(0x82),
+            'З' => Some(0x83),
+            'И' => Some(0x84),
+            'Й' => Some(0x85),
+            'Л' => Some(0x86),
+            'П' => Some(0x87),
+            'У' => Some(0x88),
+            'Ц' => Some(0x89),
+            'Ч' => Some(0x8A),
+            'Ш' => Some(0x8B),
+            'Щ' => Some(0x8C),
+            'Ъ' => Some(0x8D),
+            'Ы' => Some(0x8E),
+            'Э' => Some(0x8F),
+            'α' => Some(0x90),
+            '♪' => Some(0x91),
+            'Γ' => Some(0x92),
+            'π' => Some(0x93),
+            'Σ' => Some(0x94),
+            'σ' => Some(0x95),
+            '♬' => Some(0x96),
+            'τ' => Some(0x97),
+            '🔔' => Some(0x98),
+            'Θ' => Some(0x99),
+            'Ω' => Some(0x9A),
+            'δ' => Some(0x9B),
+            '∞' => Some(0x9C),
+            '♥' => Some(0x9D),
+            'ε' => Some(0x9E),
+            '∩' => Some(0x9F),
+            '¡' => Some(0xA1),
+            '¢' => Some(0xA2),
+            '£' => Some(0xA3),
+            '¤' => Some(0xA4),
+            '¥' => Some(0xA5),
+            '¦' => Some(0xA6),
+            '§' => Some(0xA7),
+            'ƒ' => Some(0xA8),
+            '©' => Some(0xA9),
+            'ª' => Some(0xAA),
+            '«' => Some(0xAB),
+            'Ю' => Some(0xAC),
+            'Я' => Some(0xAD),
+            '®' => Some(0xAE),
+            '‘' => Some(0xAF),
+            '°' => Some(0xB0),
+            '±' => Some(0xB1),
+            '²' => Some(0xB2),
+            '³' => Some(0xB3),
+            '₧' => Some(0xB4),
+            'µ' | 'μ' => Some(0xB5),
+            '¶' => Some(0xB6),
+            '·' => Some(0xB7),
+            'ω' => Some(0xB8),
+            '¹' => Some(0xB9),
+            'º' => Some(0xBA),
+            '»' => Some(0xBB),
+            '¼' => Some(0xBC),
+            '½' => Some(0xBD),
+            '¾' => Some(0xBE),
+            '¿' => Some(0xBF),
+            _ => None,
+        }
+    }
+}
+
+/// A00 ROM codes for the full-width katakana U+30A1-U+30FC, each with the voiced sound mark that follows it, or 0
+#[cfg(feature = "charset-a00")]
+const KATAKANA_A00: [(u8, u8); 92] = [
+    (0xA7, 0),
+    (0xB1, 0),
+    (0xA8, 0),
+    (0xB2, 0),
+    (0xA9, 0),
+    (0xB3, 0),
+    (0xAA, 0),
+    (0xB4, 0),
+    (0xAB, 0),
+    (0xB5, 0),
+    (0xB6, 0),
+    (0xB6, 0xDE),
+    (0xB7, 0),
+    (0xB7, 0xDE),
+    (0xB8, 0),
+    (0xB8, 0xDE),
+    (0xB9, 0),
+    (0xB9, 0xDE),
+    (0xBA, 0),
+    (0xBA, 0xDE),
+    (0xBB, 0),
+    (0xBB, 0xDE),
+    (0xBC, 0),
+    (0xBC, 0xDE),
+    (0xBD, 0),
+    (0xBD, 0xDE),
+    (0xBE, 0),
+    (0xBE, 0xDE),
+    (0xBF, 0),
+    (0xBF, 0xDE),
+    (0xC0, 0),
+    (0xC0, 0xDE),
+    (0xC1, 0),
+    (0xC1, 0xDE),
+    (0xAF, 0),
+    (0xC2, 0),
+    (0xC2, 0xDE),
+    (0xC3, 0),
+    (0xC3, 0xDE),
+    (0xC4, 0),
+    (0xC4, 0xDE),
+    (0xC5, 0),
+    (0xC6, 0),
+    (0xC7, 0),
+    (0xC8, 0),
+    (0xC9, 0),
+    (0xCA, 0),
+    (0xCA, 0xDE),
+    (0xCA, 0xDF),
+    (0xCB, 0),
+    (0xCB, 0xDE),
+    (0xCB, 0xDF),
+    (0xCC, 0),
+    (0xCC, 0xDE),
+    (0xCC, 0xDF),
+    (0xCD, 0),
+    (0xCD, 0xDE),
+    (0xCD, 0xDF),
+    (0xCE, 0),
+    (0xCE, 0xDE),
+    (0xCE, 0xDF),
+    (0xCF, 0),
+    (0xD0, 0),
+    (0xD1, 0),
+    (0xD2, 0),
+    (0xD3, 0),
+    (0xAC, 0),
+    (0xD4, 0),
+    (0xAD, 0),
+    (0xD5, 0),
+    (0xAE, 0),
+    (0xD6, 0),
+    (0xD7, 0),
+    (0xD8, 0),
+    (0xD9, 0),
+    (0xDA, 0),
+    (0xDB, 0),
+    (0xDC, 0),
+    (0xDC, 0),
+    (0xB2, 0),
+    (0xB4, 0),
+    (0xA6, 0),
+    (0xDD, 0),
+    (0xB3, 0xDE),
+    (0xB6, 0),
+    (0xB9, 0),
+    (0xDC, 0xDE),
+    (0xB2, 0xDE),
+    (0xB4, 0xDE),
+    (0xA6, 0xDE),
+    (0xA5, 0),
+    (0xB0, 0),
+];
+
+/// Convert a katakana character or Japanese punctuation mark to A00 ROM codes. Full-width katakana with a voiced
+/// sound mark become the base katakana followed by the mark, as there are no precomposed glyphs in the ROM.
+#[cfg(feature = "charset-a00")]
+pub(crate) fn katakana_to_a00(c: char) -> Option<(u8, Option<u8>)> {
+    let code = match c {
+        '\u{30A1}'..='\u{30FC}' => {
+            let (code, mark) = KATAKANA_A00[(c as u32 - 0x30A1) as usize];
+            return Some((code, (mark != 0).then_some(mark)));
+        }
+        '\u{FF61}'..='\u{FF9F}' => (c as u32 - 0xFF61) as u8 + 0xA1,
+        '。' => 0xA1,
+        '「' => 0xA2,
+        '」' => 0xA3,
+        '、' => 0xA4,
+        '゛' => 0xDE,
+        '゜' => 0xDF,
+        _ => return None,
+    };
+    Some((code, None))
+}
+
+/// ASCII approximation of a character with no glyph on the display, for `LcdBackpack::set_transliteration()`
+pub(crate) fn transliterate(c: char) -> Option<&'static str> {
+    let text = match c {
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ç' | 'Ć' | 'Č' => "C",
+        'ç' | 'ć' | 'č' => "c",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'ð' | 'ď' | 'đ' => "d",
+        'È'..='Ë' | 'Ē' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è'..='ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'Ğ' => "G",
+        'ğ' => "g",
+        'Ì'..='Ï' | 'Ī' | 'Į' | 'İ' => "I",
+        'ì'..='ï' | 'ī' | 'į' | 'ı' => "i",
+        'Ł' => "L",
+        'ł' => "l",
+        'Ñ' | 'Ń' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ň' => "n",
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ő' => "O",
+        'ò'..='ö' | 'ø' | 'ō' | 'ő' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ř' => "R",
+        'ř' => "r",
+        'Ś' | 'Š' | 'Ş' => "S",
+        'ś' | 'š' | 'ş' => "s",
+        'ß' => "ss",
+        'Ť' => "T",
+        'ť' => "t",
+        'Þ' => "Th",
+        'þ' => "th",
+        'Ù'..='Ü' | 'Ū' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ù'..='ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'Ý' | 'Ÿ' => "Y",
+        'ý' | 'ÿ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        '‘' | '’' | '‚' | '′' => "'",
+        '“' | '”' | '„' | '″' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '−' => "-",
+        '…' => "...",
+        '•' | '·' => ".",
+        '«' => "<<",
+        '»' => ">>",
+        '¡' => "!",
+        '¿' => "?",
+        '×' => "x",
+        '÷' => "/",
+        '±' => "+-",
+        '¹' => "1",
+        '²' => "2",
+        '³' => "3",
+        '½' => "1/2",
+        'µ' | 'μ' => "u",
+        '©' => "(C)",
+        '®' => "(R)",
+        '™' => "TM",
+        '€' => "EUR",
+        '\u{A0}' | '\u{2002}'..='\u{200A}' => " ",
+        _ => return None,
+    };
+    Some(text)
+}
@@ -0,0 +1,37 @@
+use embedded_hal::blocking::delay::{DelayMs, DelayUs};
+
+/// Wraps a mutable reference to a delay object so the LCD backpack can borrow the delay instead of owning it. This lets a
+/// single delay or timer be shared with other drivers, which can use it again once the LCD backpack is dropped or
+/// released.
+///
+/// ```rust,ignore
+/// let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, DelayRef::new(&mut delay));
+/// ```
+pub struct DelayRef<'a, D> {
+    delay: &'a mut D,
+}
+
+impl<'a, D> DelayRef<'a, D> {
+    /// Create a new delay reference
+    pub fn new(delay: &'a mut D) -> Self {
+        Self { delay }
+    }
+}
+
+impl<D, UXX> DelayMs<UXX> for DelayRef<'_, D>
+where
+    D: DelayMs<UXX>,
+{
+    fn delay_ms(&mut self, ms: UXX) {
+        self.delay.delay_ms(ms);
+    }
+}
+
+impl<D, UXX> DelayUs<UXX> for DelayRef<'_, D>
+where
+    D: DelayUs<UXX>,
+{
+    fn delay_us(&mut self, us: UXX) {
+        self.delay.delay_us(us);
+    }
+}
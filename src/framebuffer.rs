@@ -0,0 +1,256 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// An in-RAM character buffer for a display of `COLS` columns and `ROWS` rows. Text is rendered into the buffer without
+/// any I2C traffic, and `flush()` then pushes the buffer to the LCD. This decouples rendering from the slow I2C bus, so
+/// a screen can be redrawn at any pace and shown in one go. The buffer keeps track of the cells that changed since the
+/// last flush and only sends those, so redrawing a whole screen every frame costs little I2C traffic when little of it
+/// changes.
+///
+/// The buffer may be larger than the display, in which case it acts as a virtual display and `pan_to()` selects the
+/// part that is shown. Content such as long status text or several pages can then be composed once and scrolled into
+/// view without rendering it again.
+///
+/// ```rust,ignore
+/// let mut frame: FrameBuffer<16, 2> = FrameBuffer::new();
+/// write!(frame.set_cursor(0, 0), "Temp {}C", temp)?;
+/// frame.flush(&mut lcd)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameBuffer<const COLS: usize, const ROWS: usize> {
+    cells: [[char; COLS]; ROWS],
+    dirty: [[bool; COLS]; ROWS],
+    cursor_col: u8,
+    cursor_row: u8,
+    view_col: u8,
+    view_row: u8,
+    // view position of the last flush, or `None` if the display content is unknown
+    flushed_view: Option<(u8, u8)>,
+}
+
+impl<const COLS: usize, const ROWS: usize> FrameBuffer<COLS, ROWS> {
+    /// Create a new frame buffer filled with spaces. All cells are sent on the first flush.
+    pub const fn new() -> Self {
+        Self {
+            cells: [[' '; COLS]; ROWS],
+            dirty: [[true; COLS]; ROWS],
+            cursor_col: 0,
+            cursor_row: 0,
+            view_col: 0,
+            view_row: 0,
+            flushed_view: None,
+        }
+    }
+
+    /// Fill the buffer with spaces and move the cursor to the home position
+    pub fn clear(&mut self) -> &mut Self {
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                self.set_cell(col, row, ' ');
+            }
+        }
+        self.home()
+    }
+
+    /// Mark every cell as changed so the next flush sends the whole buffer, such as after the LCD was cleared or
+    /// written to directly
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.dirty = [[true; COLS]; ROWS];
+        self.flushed_view = None;
+        self
+    }
+
+    /// Scroll the buffer content up by `n` rows, filling the rows uncovered at the bottom with spaces. The HD44780 can
+    /// only shift the display sideways, so this gives line scrolling in software. Only the cells that change are sent
+    /// on the next flush. The cursor does not move.
+    pub fn scroll_up(&mut self, n: u8) -> &mut Self {
+        let n = n as usize;
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                let c = if row + n < ROWS {
+                    self.cells[row + n][col]
+                } else {
+                    ' '
+                };
+                self.set_cell(col, row, c);
+            }
+        }
+        self
+    }
+
+    /// Scroll the buffer content down by `n` rows, filling the rows uncovered at the top with spaces. Only the cells
+    /// that change are sent on the next flush. The cursor does not move.
+    pub fn scroll_down(&mut self, n: u8) -> &mut Self {
+        let n = n as usize;
+        for row in (0..ROWS).rev() {
+            for col in 0..COLS {
+                let c = if row >= n {
+                    self.cells[row - n][col]
+                } else {
+                    ' '
+                };
+                self.set_cell(col, row, c);
+            }
+        }
+        self
+    }
+
+    /// Select the buffer column and row shown in the top left corner of the display, for buffers larger than the
+    /// display. The position is limited on flush so the view stays within the buffer. Moving the view redraws the whole
+    /// display on the next flush.
+    pub fn pan_to(&mut self, col: u8, row: u8) -> &mut Self {
+        self.view_col = col;
+        self.view_row = row;
+        self
+    }
+
+    /// Get the buffer column and row requested for the top left corner of the display with `pan_to()`
+    pub fn view_position(&self) -> (u8, u8) {
+        (self.view_col, self.view_row)
+    }
+
+    /// Returns `true` if any cell changed since the last flush
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.iter().flatten().any(|&dirty| dirty)
+    }
+
+    /// Move the cursor to the home position
+    pub fn home(&mut self) -> &mut Self {
+        self.set_cursor(0, 0)
+    }
+
+    /// Move the cursor to the specified column and row. Characters written while the cursor is outside the buffer are
+    /// dropped.
+    pub fn set_cursor(&mut self, col: u8, row: u8) -> &mut Self {
+        self.cursor_col = col;
+        self.cursor_row = row;
+        self
+    }
+
+    /// Get the current cursor position as a (column, row) tuple
+    pub fn get_cursor(&self) -> (u8, u8) {
+        (self.cursor_col, self.cursor_row)
+    }
+
+    /// Write a character at the cursor position and advance the cursor. Characters past the end of the row are
+    /// dropped.
+    pub fn write_char(&mut self, c: char) -> &mut Self {
+        let (col, row) = (self.cursor_col as usize, self.cursor_row as usize);
+        if row < ROWS && col < COLS {
+            self.set_cell(col, row, c);
+        }
+        self.cursor_col = self.cursor_col.saturating_add(1);
+        self
+    }
+
+    /// Write a string at the cursor position. Characters past the end of the row are dropped.
+    pub fn print(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.write_char(c);
+        }
+        self
+    }
+
+    /// Get the character at the specified column and row, or `None` if the position is outside the buffer
+    pub fn get_char(&self, col: u8, row: u8) -> Option<char> {
+        self.cells
+            .get(row as usize)
+            .and_then(|cells| cells.get(col as usize))
+            .copied()
+    }
+
+    /// Get a copy of the buffer's contents, such as for checking what a screen shows in tests or dumping it over a
+    /// debug channel
+    pub fn screenshot(&self) -> [[char; COLS]; ROWS] {
+        self.cells
+    }
+
+    /// Iterate over the rows of the buffer's contents from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[char; COLS]> {
+        self.cells.iter()
+    }
+
+    /// Push the changed cells in view to the LCD. Runs of changed cells are written after a single cursor move, and
+    /// runs separated by one unchanged cell are joined, as rewriting that cell costs no more than moving the cursor.
+    /// Each character is mapped with the LCD's character mapper and takes up one column. Afterwards the LCD's cursor is
+    /// moved to the buffer's cursor position if that is in view. Text is assumed to flow left to right.
+    pub fn flush<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let view_cols = COLS.min(lcd.cols() as usize);
+        let view_rows = ROWS.min(lcd.rows() as usize);
+        let view_col = (self.view_col as usize).min(COLS - view_cols);
+        let view_row = (self.view_row as usize).min(ROWS - view_rows);
+        // a different view puts every cell in a new place on the display
+        let view = Some((view_col as u8, view_row as u8));
+        let redraw = self.flushed_view != view;
+
+        for row in view_row..view_row + view_rows {
+            let dirty = self.dirty[row];
+            let changed = |col: usize| redraw || dirty[col];
+            let mut col = view_col;
+            while col < view_col + view_cols {
+                if !changed(col) {
+                    col += 1;
+                    continue;
+                }
+                let start = col;
+                let mut end = col + 1;
+                loop {
+                    if end < view_col + view_cols && changed(end) {
+                        end += 1;
+                    } else if end + 1 < view_col + view_cols && changed(end + 1) {
+                        end += 2;
+                    } else {
+                        break;
+                    }
+                }
+                lcd.set_cursor((start - view_col) as u8, (row - view_row) as u8)?;
+                for col in start..end {
+                    lcd.write_data(lcd.single_char_code(self.cells[row][col]))?;
+                }
+                self.dirty[row][start..end].fill(false);
+                col = end;
+            }
+        }
+        // only a view drawn in full is recorded, so a failed write redraws the whole view on the next flush
+        self.flushed_view = view;
+
+        let cursor_col = (self.cursor_col as usize).wrapping_sub(view_col);
+        let cursor_row = (self.cursor_row as usize).wrapping_sub(view_row);
+        if cursor_col < view_cols && cursor_row < view_rows {
+            lcd.set_cursor(cursor_col as u8, cursor_row as u8)?;
+        }
+        Ok(())
+    }
+
+    /// Store a character in a cell, marking the cell as changed if the character differs
+    fn set_cell(&mut self, col: usize, row: usize, c: char) {
+        if self.cells[row][col] != c {
+            self.cells[row][col] = c;
+            self.dirty[row][col] = true;
+        }
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Default for FrameBuffer<COLS, ROWS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implement the `core::fmt::Write` trait for the frame buffer, allowing it to be used with the `write!` macro.
+impl<const COLS: usize, const ROWS: usize> core::fmt::Write for FrameBuffer<COLS, ROWS> {
+    fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
+        self.print(s);
+        Ok(())
+    }
+}
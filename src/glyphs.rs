@@ -0,0 +1,123 @@
+//! Ready-made 5x8 custom character bitmaps. Each glyph is a character map that can be passed to
+//! `LcdBackpack::create_char()`, `LcdBackpack::load_glyph()` or `LcdBackpack::load_glyphs()`.
+//!
+//! ```rust,ignore
+//! use adafruit_lcd_backpack::glyphs;
+//!
+//! let [degree, heart] = lcd.load_glyphs([glyphs::DEGREE, glyphs::HEART])?;
+//! lcd.print("21")?.write_custom_char(degree)?.print("C ")?.write_custom_char(heart)?;
+//! ```
+
+/// Degree sign
+pub const DEGREE: [u8; 8] = [
+    0b00110, 0b01001, 0b01001, 0b00110, 0b00000, 0b00000, 0b00000, 0b00000,
+];
+
+/// Arrow pointing up
+pub const ARROW_UP: [u8; 8] = [
+    0b00100, 0b01110, 0b10101, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000,
+];
+
+/// Arrow pointing down
+pub const ARROW_DOWN: [u8; 8] = [
+    0b00100, 0b00100, 0b00100, 0b00100, 0b10101, 0b01110, 0b00100, 0b00000,
+];
+
+/// Arrow pointing left
+pub const ARROW_LEFT: [u8; 8] = [
+    0b00000, 0b00100, 0b01000, 0b11111, 0b01000, 0b00100, 0b00000, 0b00000,
+];
+
+/// Arrow pointing right
+pub const ARROW_RIGHT: [u8; 8] = [
+    0b00000, 0b00100, 0b00010, 0b11111, 0b00010, 0b00100, 0b00000, 0b00000,
+];
+
+/// Bell
+pub const BELL: [u8; 8] = [
+    0b00100, 0b01110, 0b01110, 0b01110, 0b11111, 0b00000, 0b00100, 0b00000,
+];
+
+/// Heart
+pub const HEART: [u8; 8] = [
+    0b00000, 0b01010, 0b11111, 0b11111, 0b01110, 0b00100, 0b00000, 0b00000,
+];
+
+/// Ohm sign
+pub const OHM: [u8; 8] = [
+    0b00000, 0b01110, 0b10001, 0b10001, 0b10001, 0b01010, 0b11011, 0b00000,
+];
+
+/// Battery charge levels from empty (index 0) to full (index 5). Each level fills one more row from the bottom.
+pub const BATTERY: [[u8; 8]; 6] = [
+    [
+        0b01110, 0b11011, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11111,
+    ],
+    [
+        0b01110, 0b11011, 0b10001, 0b10001, 0b10001, 0b10001, 0b11111, 0b11111,
+    ],
+    [
+        0b01110, 0b11011, 0b10001, 0b10001, 0b10001, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b01110, 0b11011, 0b10001, 0b10001, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b01110, 0b11011, 0b10001, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b01110, 0b11011, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+];
+
+/// Progress bar blocks with 1 (index 0) to 5 (index 4) columns filled from the left
+pub const PROGRESS: [[u8; 8]; 5] = [
+    [
+        0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000,
+    ],
+    [
+        0b11000, 0b11000, 0b11000, 0b11000, 0b11000, 0b11000, 0b11000, 0b11000,
+    ],
+    [
+        0b11100, 0b11100, 0b11100, 0b11100, 0b11100, 0b11100, 0b11100, 0b11100,
+    ],
+    [
+        0b11110, 0b11110, 0b11110, 0b11110, 0b11110, 0b11110, 0b11110, 0b11110,
+    ],
+    [
+        0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+];
+
+/// Vertical bar blocks with 1 (index 0) to 8 (index 7) rows filled from the bottom
+pub const VBAR: [[u8; 8]; 8] = [
+    [
+        0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111,
+    ],
+    [
+        0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111, 0b11111,
+    ],
+    [
+        0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b00000, 0b00000, 0b00000, 0b00000, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b00000, 0b00000, 0b00000, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b00000, 0b00000, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b00000, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+];
+
+/// Backslash, which is a yen sign in the A00 character ROM
+pub const BACKSLASH: [u8; 8] = [
+    0b00000, 0b10000, 0b01000, 0b00100, 0b00010, 0b00001, 0b00000, 0b00000,
+];
@@ -0,0 +1,51 @@
+use crate::LcdBackpack;
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+use mcp230xx::Register;
+
+/// The state of the backpack found by `LcdBackpack::health_check()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// The backpack responds and its registers match the driver's state
+    Healthy,
+    /// The backpack did not respond on the I2C bus, for example because it was unplugged
+    NotResponding,
+    /// Pins driving the LCD are no longer outputs, as happens when the MCP23008 was reset or lost power
+    PinsNotOutputs,
+    /// The output latch does not hold the value the driver last wrote to it
+    OutputMismatch,
+}
+
+impl<I2C, I2C_ERR, D> LcdBackpack<I2C, D>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    /// Check that the backpack still responds and that its IODIR and OLAT registers match the state the driver expects.
+    /// This takes two register reads, so it is cheap enough to call periodically on long running devices. Bus errors
+    /// count towards automatic re-initialization, see `set_auto_reinit()`.
+    pub fn health_check(&mut self) -> HealthStatus {
+        if self.flush_update().is_err() {
+            return HealthStatus::NotResponding;
+        }
+        let Ok(direction) = self.read_register_uncached(Register::IODIR) else {
+            return HealthStatus::NotResponding;
+        };
+        let outputs = [self.pins.rs, self.pins.enable, self.pins.backlight]
+            .into_iter()
+            .chain(self.pins.data)
+            .fold(0u8, |mask, pin| mask | (1 << (pin as u8)));
+        if direction & outputs != 0 {
+            return HealthStatus::PinsNotOutputs;
+        }
+        let Ok(latch) = self.read_register_uncached(Register::OLAT) else {
+            return HealthStatus::NotResponding;
+        };
+        match self.output_latch {
+            Some(expected) if expected != latch => HealthStatus::OutputMismatch,
+            _ => HealthStatus::Healthy,
+        }
+    }
+}
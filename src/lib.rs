@@ -12,7 +12,7 @@
 //! To create a new LCD backpack, use the `new` method. This will return a new LCD backpack object. Pass it the type of LCD display you
 //! are using, the I2C bus, and the delay object. Both the I2C Bus and Delay objects must implement the relevant embedded-hal traits.
 //!
-//! ```rust
+//! ```rust,ignore
 //! // The embedded-hal traits are used to define the I2C bus and delay objects
 //! use embedded_hal::{
 //!     blocking::delay::{DelayMs, DelayUs},
@@ -34,8 +34,13 @@
 //!    panic!("Error initializing LCD");
 //! }
 //! ```
+//! Alternatively, `new_init` creates and initializes the LCD in one call, returning an error if the backpack does not
+//! respond or initialization fails:
+//! ```rust,ignore
+//! let mut lcd = LcdBackpack::new_init(LcdDisplayType::Lcd16x2, i2c, delay)?;
+//! ```
 //! This library supports the `core::fmt::Write` trait, allowing it to be used with the `write!` macro. For example:
-//! ```rust
+//! ```rust,ignore
 //! use core::fmt::Write;
 //!
 //! // write a string to the LCD
@@ -46,7 +51,7 @@
 //! The various methods for controlling the LCD are also available. Each returns a `Result` that wraps the LCD backpack object. This
 //! allows you to chain the methods together. For example:
 //!
-//! ```rust
+//! ```rust,ignore
 //! // clear the display and home the cursor before writing a string
 //! if let Err(_e) = write!(lcd.clear()?.home()?, "Hello, world!") {
 //!  panic!("Error writing to LCD");
@@ -55,11 +60,67 @@
 
 #![no_std]
 #![allow(dead_code, non_camel_case_types, non_upper_case_globals)]
+use ansi::{AnsiAction, AnsiParser};
 use embedded_hal::{
     blocking::delay::{DelayMs, DelayUs},
     blocking::i2c::{Write, WriteRead},
 };
-use mcp230xx::{Direction, Level, Mcp23008, Mcp230xx, Register};
+use mcp230xx::{Direction, Level, Register};
+use number::NumberBuffer;
+use shadow::Shadow;
+
+mod ansi;
+#[cfg(feature = "benchmark")]
+mod benchmark;
+mod builder;
+mod charset;
+mod delay;
+mod framebuffer;
+pub mod glyphs;
+mod health;
+mod menu;
+mod mirror;
+mod number;
+mod pages;
+#[cfg(feature = "queue")]
+mod queue;
+mod region;
+mod row_writer;
+mod scan;
+mod shadow;
+mod widgets;
+mod wrap;
+
+#[cfg(feature = "benchmark")]
+pub use benchmark::BenchmarkResult;
+pub use builder::LcdBackpackBuilder;
+pub use charset::CharacterMapper;
+#[cfg(feature = "charset-a00")]
+pub use charset::RomA00Mapper;
+#[cfg(feature = "charset-a02")]
+pub use charset::RomA02Mapper;
+pub use delay::DelayRef;
+pub use framebuffer::FrameBuffer;
+pub use health::HealthStatus;
+pub use mcp230xx::Mcp23008;
+pub use menu::{Menu, MenuEvent, MenuInput, MenuItem};
+pub use mirror::MirroredLcd;
+pub use pages::Pages;
+#[cfg(feature = "queue")]
+pub use queue::{enqueue_text, CommandConsumer, CommandProducer, CommandQueue, LcdCommand};
+pub use region::Region;
+pub use row_writer::RowWriter;
+pub use scan::{scan_for_backpack, ScanResult};
+#[cfg(feature = "queue")]
+pub use widgets::Console;
+pub use widgets::{
+    Align, BigDigits, BlinkingText, Clock, Column, ConfirmDialog, DialogEvent, DialogInput,
+    EditorCursor, EditorEvent, EditorInput, Field, HBarGraph, Layout, ListEvent, ListPicker,
+    Marquee, MarqueeMode, Segment, SensorField, Sparkline, Spinner, SpinnerStyle, Splash,
+    StatusLine, Table, Trend, Typewriter, VBarGraph, ValueEditor,
+};
+
+const DEFAULT_I2C_ADDRESS: u8 = 0x20;
 
 const RS_PIN: Mcp23008 = Mcp23008::P1;
 const ENABLE_PIN: Mcp23008 = Mcp23008::P2;
@@ -72,6 +133,32 @@ const BACKLIGHT_PIN: Mcp23008 = Mcp23008::P7;
 // data pins are in order from least significant bit to most significant bit
 const DATA_PINS: [Mcp23008; 4] = [DATA_D4_PIN, DATA_D5_PIN, DATA_D6_PIN, DATA_D7_PIN];
 
+// MCP23008 configuration
+const IOCON_SEQOP: u8 = 0x20; //  Disable the address pointer increment so multi-byte writes repeat the same register
+
+// one I2C byte takes at least 5us at the MCP23008's 1.7MHz maximum bus speed, longer than the enable pulse needs
+const COMBINED_WRITE_PULSE_US: u16 = 5;
+
+// register writes queued by begin_update(), including the leading register address byte
+const UPDATE_BUFFER_LEN: usize = 32;
+
+// Largest geometry a display can have, set by the HD44780's 80 bytes of DDRAM
+const MAX_ROWS: u8 = 4;
+const MAX_COLS: u8 = 40;
+
+// default timing
+const POWER_ON_DELAY_MS: u16 = 40; //  HD44780 needs 40ms after power rises above 2.7V
+const ENABLE_PULSE_US: u16 = 1; //  Enable pulse width and data setup time
+const NIBBLE_DELAY_US: u16 = 100; //  Wait after each nibble for the command to settle
+const CLEAR_HOME_DELAY_MS: u16 = 2; //  Clear and home commands take up to 1.52ms
+
+// 4 bit mode initialization waits from the HD44780 datasheet
+const INIT_FIRST_WAIT_US: u16 = 4100; //  Wait after the first function set
+const INIT_SECOND_WAIT_US: u16 = 100; //  Wait after the second function set
+
+const DEFAULT_TAB_WIDTH: u8 = 4;
+const DEFAULT_REPLACEMENT_CHAR: u8 = b'?';
+
 // commands
 const LCD_CMD_CLEARDISPLAY: u8 = 0x01; //  Clear display, set cursor position to zero
 const LCD_CMD_RETURNHOME: u8 = 0x02; //  Set cursor position to zero
@@ -110,32 +197,279 @@ const LCD_FLAG_1LINE: u8 = 0x00; //  LCD 1 line mode
 const LCD_FLAG_5x10_DOTS: u8 = 0x04; //  10 pixel high font mode
 const LCD_FLAG_5x8_DOTS: u8 = 0x00; //  8 pixel high font mode
 
+// extended function set flags and commands of SSD1803A/US2066 compatible controllers
+const LCD_FLAG_DOUBLEHEIGHT: u8 = 0x04; //  Double height font mode (shares its bit with LCD_FLAG_5x10_DOTS)
+const LCD_FLAG_EXTENDED_REGISTER: u8 = 0x02; //  Selects the extended instruction set (RE bit)
+const LCD_CMD_DOUBLEHEIGHTFORMAT: u8 = 0x10; //  Sets the double height format, extended instruction set only
+
+/// The MCP23008 pins the LCD is wired to. The default mapping is the wiring of the Adafruit I2C LCD backpack. The LCD's
+/// RW pin is expected to be tied low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PinMapping {
+    /// Register select pin
+    pub rs: Mcp23008,
+    /// Enable pin
+    pub enable: Mcp23008,
+    /// Data pins D4 through D7, in order from least significant bit to most significant bit
+    pub data: [Mcp23008; 4],
+    /// Backlight pin
+    pub backlight: Mcp23008,
+}
+
+impl Default for PinMapping {
+    fn default() -> Self {
+        Self {
+            rs: RS_PIN,
+            enable: ENABLE_PIN,
+            data: DATA_PINS,
+            backlight: BACKLIGHT_PIN,
+        }
+    }
+}
+
+/// The delays used when driving the LCD. The defaults are conservative and work with standard HD44780 modules. The
+/// profile can be set with `LcdBackpackBuilder::timing()` or changed at any time with `LcdBackpack::set_timing()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimingProfile {
+    /// Wait after power up before the first command is sent, in milliseconds. Can be skipped with
+    /// `skip_power_on_delay()` when power is known to have been stable long enough before `init()` is called.
+    pub power_on_delay_ms: u16,
+    /// Width of the enable pulse, and the data setup time before it, in microseconds. Up to 5 microseconds, the pulse is
+    /// sent in one multi-byte I2C write with the data, as the transfer time of each byte is long enough. Longer pulses
+    /// are timed with the delay between separate writes.
+    pub enable_pulse_us: u16,
+    /// Wait after each nibble is latched, or after each byte when both nibbles are sent in one I2C write, in
+    /// microseconds
+    pub nibble_delay_us: u16,
+    /// Wait after a clear or home command, in milliseconds
+    pub clear_home_delay_ms: u16,
+}
+
+impl TimingProfile {
+    /// The default delays, which follow the HD44780 datasheet with some margin on the per-nibble wait
+    pub const fn standard() -> Self {
+        Self {
+            power_on_delay_ms: POWER_ON_DELAY_MS,
+            enable_pulse_us: ENABLE_PULSE_US,
+            nibble_delay_us: NIBBLE_DELAY_US,
+            clear_home_delay_ms: CLEAR_HOME_DELAY_MS,
+        }
+    }
+
+    /// The HD44780 datasheet minimums, for genuine modules where every bit of latency counts
+    pub const fn fast() -> Self {
+        Self {
+            power_on_delay_ms: 40,
+            enable_pulse_us: 1,
+            nibble_delay_us: 40,
+            clear_home_delay_ms: 2,
+        }
+    }
+
+    /// Longer delays for slow controllers, such as OLED character modules and some HD44780 clones
+    pub const fn slow() -> Self {
+        Self {
+            power_on_delay_ms: 100,
+            enable_pulse_us: 2,
+            nibble_delay_us: 200,
+            clear_home_delay_ms: 5,
+        }
+    }
+
+    /// Skip the power on delay in `init()`, such as when re-initializing the LCD or when the application has already
+    /// spent more than 40 milliseconds since power up
+    pub const fn skip_power_on_delay(mut self) -> Self {
+        self.power_on_delay_ms = 0;
+        self
+    }
+}
+
+impl Default for TimingProfile {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Counts of the I2C traffic and LCD operations since the LCD backpack was created or `reset_stats()` was called, for
+/// measuring the cost of refreshing the display. The counts wrap around on overflow.
+#[cfg(feature = "stats")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LcdStats {
+    /// Number of I2C transactions, including register reads
+    pub transactions: u32,
+    /// Number of bytes transferred over I2C in either direction, excluding the address bytes
+    pub bytes: u32,
+    /// Number of commands sent to the LCD
+    pub commands: u32,
+    /// Number of data bytes written to the LCD
+    pub data_writes: u32,
+}
+
+/// Handle to a custom character programmed with `LcdBackpack::create_char()`. The handle can only be obtained by
+/// programming a custom character, so printing it never shows an unprogrammed CGRAM location.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomChar {
+    location: u8,
+}
+
+impl CustomChar {
+    /// Get the CGRAM location of the custom character
+    pub fn location(&self) -> u8 {
+        self.location
+    }
+
+    /// Get the character that prints the custom character when passed to `print()` or `write!`
+    pub fn as_char(&self) -> char {
+        self.location as char
+    }
+}
+
+/// Completion token for a clear or home command started with `LcdBackpack::clear_nonblocking()` or
+/// `LcdBackpack::home_nonblocking()`. Nothing else may be sent to the display until the command is complete.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PendingCommand {
+    started_ms: u32,
+    duration_ms: u16,
+}
+
+impl PendingCommand {
+    /// Check whether the command has completed, given the current time in milliseconds. The time may wrap around. One
+    /// extra millisecond is allowed for the granularity of the clock.
+    pub fn is_complete(&self, now_ms: u32) -> bool {
+        now_ms.wrapping_sub(self.started_ms) > self.duration_ms as u32
+    }
+}
+
+/// How `print()` and the `core::fmt::Write` implementation handle text that runs past the end of the row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Text is sent to the LCD as is and continues into the controller's DDRAM, which may be offscreen or on another
+    /// row depending on the display type
+    #[default]
+    Unchecked,
+    /// Characters past the end of the row are dropped
+    Truncate,
+    /// Text continues at the start of the next row, wrapping from the last row back to the first row. The cursor moves
+    /// to the next row as soon as any data write reaches the end of the row, so the visible cursor follows the text.
+    WrapToNextRow,
+    /// Writing past the end of the row returns `Error::ColumnOutOfRange`
+    Error,
+}
+
+/// The fill used to right align numbers in a fixed width field with the `print_*_padded()` methods
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Padding {
+    /// Pad with leading spaces, such as `  42`
+    #[default]
+    Space,
+    /// Pad with leading zeros after any sign, such as `0042` or `-042`
+    Zero,
+}
+
+/// The rows that are shown in double height when double height mode is enabled. Positions refer to the rows of a 4 row
+/// display; rows that are merged into a double height row are no longer shown on their own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DoubleHeightMode {
+    /// Rows 0 and 1 are merged into one double height row
+    Top = 0x00,
+    /// Rows 1 and 2 are merged into one double height row
+    Middle = 0x04,
+    /// Rows 2 and 3 are merged into one double height row
+    Bottom = 0x08,
+    /// Rows 0 and 1, and rows 2 and 3, are each merged into a double height row
+    TopAndBottom = 0x0C,
+}
+
 /// The type of LCD display. This is used to determine the number of rows and columns, and the row offsets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LcdDisplayType {
+    /// 40x2 display
+    Lcd40x2,
     /// 20x4 display
     Lcd20x4,
     /// 20x2 display
     Lcd20x2,
+    /// 16x4 display
+    Lcd16x4,
     /// 16x2 display
     Lcd16x2,
+    /// 8x2 display
+    Lcd8x2,
+    /// 8x1 display
+    Lcd8x1,
+    /// 16x1 display that is internally organized as 8x2. Columns 8-15 are addressed as the second line.
+    Lcd16x1TypeA,
+    /// 16x1 display with a single contiguous line of addresses
+    Lcd16x1TypeB,
+    /// Display with a user supplied geometry. `offsets` are the DDRAM addresses of the first column of each row. Between
+    /// 1 and 4 rows and between 1 and 40 columns are supported. Single row displays run the controller in 1 line mode.
+    Custom {
+        /// Number of columns
+        cols: u8,
+        /// Number of rows
+        rows: u8,
+        /// DDRAM address of the first column of each row
+        offsets: [u8; 4],
+    },
 }
 
 impl LcdDisplayType {
     /// Get the number of rows for the display type
     const fn rows(&self) -> u8 {
         match self {
+            LcdDisplayType::Lcd40x2 => 2,
             LcdDisplayType::Lcd20x4 => 4,
             LcdDisplayType::Lcd20x2 => 2,
+            LcdDisplayType::Lcd16x4 => 4,
             LcdDisplayType::Lcd16x2 => 2,
+            LcdDisplayType::Lcd8x2 => 2,
+            LcdDisplayType::Lcd8x1 => 1,
+            LcdDisplayType::Lcd16x1TypeA => 1,
+            LcdDisplayType::Lcd16x1TypeB => 1,
+            LcdDisplayType::Custom { rows, .. } => {
+                if *rows > MAX_ROWS {
+                    MAX_ROWS
+                } else if *rows == 0 {
+                    1
+                } else {
+                    *rows
+                }
+            }
         }
     }
 
     /// Get the number of columns for the display type
     const fn cols(&self) -> u8 {
         match self {
+            LcdDisplayType::Lcd40x2 => 40,
             LcdDisplayType::Lcd20x4 => 20,
             LcdDisplayType::Lcd20x2 => 20,
+            LcdDisplayType::Lcd16x4 => 16,
             LcdDisplayType::Lcd16x2 => 16,
+            LcdDisplayType::Lcd8x2 => 8,
+            LcdDisplayType::Lcd8x1 => 8,
+            LcdDisplayType::Lcd16x1TypeA => 16,
+            LcdDisplayType::Lcd16x1TypeB => 16,
+            LcdDisplayType::Custom { cols, .. } => {
+                if *cols > MAX_COLS {
+                    MAX_COLS
+                } else if *cols == 0 {
+                    1
+                } else {
+                    *cols
+                }
+            }
+        }
+    }
+
+    /// Check that a custom geometry has at least one row and column and fits the controller's DDRAM
+    const fn is_valid(&self) -> bool {
+        match self {
+            LcdDisplayType::Custom { cols, rows, .. } => {
+                *rows > 0 && *rows <= MAX_ROWS && *cols > 0 && *cols <= MAX_COLS
+            }
+            _ => true,
         }
     }
 
@@ -143,20 +477,90 @@ impl LcdDisplayType {
     /// For displays with less than 4 rows, the unused rows will be set to offsets offscreen.
     const fn row_offsets(&self) -> [u8; 4] {
         match self {
+            LcdDisplayType::Lcd40x2 => [0x00, 0x40, 0x00, 0x40],
             LcdDisplayType::Lcd20x4 => [0x00, 0x40, 0x14, 0x54],
             LcdDisplayType::Lcd20x2 => [0x00, 0x40, 0x00, 0x40],
+            LcdDisplayType::Lcd16x4 => [0x00, 0x40, 0x10, 0x50],
             LcdDisplayType::Lcd16x2 => [0x00, 0x40, 0x10, 0x50],
+            LcdDisplayType::Lcd8x2 => [0x00, 0x40, 0x08, 0x48],
+            LcdDisplayType::Lcd8x1 => [0x00, 0x40, 0x08, 0x48],
+            LcdDisplayType::Lcd16x1TypeA => [0x00, 0x40, 0x00, 0x40],
+            LcdDisplayType::Lcd16x1TypeB => [0x00, 0x40, 0x10, 0x50],
+            LcdDisplayType::Custom { offsets, .. } => *offsets,
+        }
+    }
+
+    /// Get the column at which the display's DDRAM addressing jumps to a non-contiguous address, if any.
+    /// Text written across this column must be re-addressed or it will continue into offscreen DDRAM.
+    const fn split_column(&self) -> Option<u8> {
+        match self {
+            LcdDisplayType::Lcd16x1TypeA => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Get the DDRAM address for the specified column and row
+    const fn ddram_address(&self, col: u8, row: u8) -> u8 {
+        match self.split_column() {
+            Some(split) if col >= split => self.row_offsets()[1].wrapping_add(col - split),
+            _ => self.row_offsets()[row as usize].wrapping_add(col),
+        }
+    }
+
+    /// Get the function set line mode flag for the display type. Displays with a single line of DDRAM addresses run the
+    /// controller in 1 line mode.
+    const fn line_mode_flag(&self) -> u8 {
+        match self {
+            LcdDisplayType::Lcd8x1 | LcdDisplayType::Lcd16x1TypeB => LCD_FLAG_1LINE,
+            LcdDisplayType::Custom { .. } if self.rows() == 1 => LCD_FLAG_1LINE,
+            _ => LCD_FLAG_2LINE,
         }
     }
 }
 
 pub struct LcdBackpack<I2C, D> {
-    register: Mcp230xx<I2C, Mcp23008>,
+    i2c: I2C,
+    address: u8,
     delay: D,
     lcd_type: LcdDisplayType,
+    pins: PinMapping,
+    timing: TimingProfile,
+    backlight: bool,
     display_function: u8,
     display_control: u8,
     display_mode: u8,
+    cursor_col: u8,
+    cursor_row: u8,
+    cgram_addressed: bool,
+    custom_chars: [Option<[u8; 8]>; 8],
+    custom_char_last_used: [u32; 8],
+    custom_char_clock: u32,
+    overflow_policy: OverflowPolicy,
+    tab_width: u8,
+    terminal_mode: bool,
+    ansi: AnsiParser,
+    idle_timeout_ms: Option<u32>,
+    idle_display_off: bool,
+    idle_ms: u32,
+    asleep: bool,
+    character_mapper: Option<&'static dyn CharacterMapper>,
+    transliteration: bool,
+    wrap_ellipsis: bool,
+    replacement_char: u8,
+    scroll_offset: u8,
+    updating: bool,
+    update_buffer: [u8; UPDATE_BUFFER_LEN],
+    update_len: usize,
+    output_latch: Option<u8>,
+    shadow: Shadow,
+    watchdog_feed: Option<fn()>,
+    reinit_after_errors: Option<u8>,
+    consecutive_errors: u8,
+    reinit_pending: bool,
+    initialized: bool,
+    last_error: Option<Error<()>>,
+    #[cfg(feature = "stats")]
+    stats: LcdStats,
 }
 
 /// Errors that can occur when using the LCD backpack
@@ -166,12 +570,100 @@ pub enum Error<I2C_ERR> {
     /// The MCP23008 interrupt pin is not found
     InterruptPinError,
     /// Row is out of range
-    RowOutOfRange,
+    RowOutOfRange {
+        /// The row that was requested
+        row: u8,
+        /// The largest valid row
+        max: u8,
+    },
     /// Column is out of range
-    ColumnOutOfRange,
-    /// Formatting error
-    #[cfg(feature = "defmt")]
+    ColumnOutOfRange {
+        /// The column that was requested
+        col: u8,
+        /// The largest valid column
+        max: u8,
+    },
+    /// Custom character location is out of range, which is 0-7 for 5x8 characters and 0-3 for 5x10 characters
+    InvalidCustomCharLocation {
+        /// The location that was requested
+        location: u8,
+        /// The largest valid location
+        max: u8,
+    },
+    /// No MCP23008 responded at the I2C address
+    DeviceNotFound {
+        /// The I2C address that was probed
+        address: u8,
+    },
+    /// A custom display geometry has zero rows or columns, or more than 4 rows or 40 columns
+    InvalidGeometry {
+        /// The number of rows that was requested
+        rows: u8,
+        /// The number of columns that was requested
+        cols: u8,
+    },
+    /// A value being formatted reported an error, such as a `Display` implementation returning `core::fmt::Error`
     FormattingError,
+    /// The LCD was sent a command or data before `init()` or `attach()` succeeded
+    NotInitialized,
+    /// A register read back from the MCP23008 did not hold the value just written to it
+    #[cfg(feature = "verify")]
+    VerificationFailed,
+}
+
+/// Errors that can occur when initializing the LCD with `init()`, identifying the step that failed so booting can be
+/// handled differently from failures during normal operation
+pub enum InitError<I2C_ERR> {
+    /// Sending the buffered writes of an update in progress failed
+    PendingUpdate(Error<I2C_ERR>),
+    /// Configuring the MCP23008's pins failed, such as when the backpack does not respond
+    ExpanderSetup(Error<I2C_ERR>),
+    /// Sending queued writes ahead of the wait for the LCD's supply to settle after power on failed
+    PowerOnDelay(Error<I2C_ERR>),
+    /// Putting the LCD into 4 bit mode and sending the display flags failed
+    FourBitMode(Error<I2C_ERR>),
+    /// Clearing the display after entering 4 bit mode failed
+    Clear(Error<I2C_ERR>),
+}
+
+impl<I2C_ERR> InitError<I2C_ERR> {
+    /// Get the error that made the step fail
+    pub fn into_error(self) -> Error<I2C_ERR> {
+        match self {
+            InitError::PendingUpdate(e)
+            | InitError::ExpanderSetup(e)
+            | InitError::PowerOnDelay(e)
+            | InitError::FourBitMode(e)
+            | InitError::Clear(e) => e,
+        }
+    }
+}
+
+impl<I2C_ERR> Error<I2C_ERR> {
+    /// Drop the I2C error, keeping which kind of error this is, so it can be stored without the I2C bus type
+    pub(crate) fn without_i2c_error(self) -> Error<()> {
+        match self {
+            Error::I2cError(_) => Error::I2cError(()),
+            Error::InterruptPinError => Error::InterruptPinError,
+            Error::RowOutOfRange { row, max } => Error::RowOutOfRange { row, max },
+            Error::ColumnOutOfRange { col, max } => Error::ColumnOutOfRange { col, max },
+            Error::InvalidCustomCharLocation { location, max } => {
+                Error::InvalidCustomCharLocation { location, max }
+            }
+            Error::DeviceNotFound { address } => Error::DeviceNotFound { address },
+            Error::InvalidGeometry { rows, cols } => Error::InvalidGeometry { rows, cols },
+            Error::FormattingError => Error::FormattingError,
+            Error::NotInitialized => Error::NotInitialized,
+            #[cfg(feature = "verify")]
+            Error::VerificationFailed => Error::VerificationFailed,
+        }
+    }
+}
+
+impl<I2C_ERR> From<InitError<I2C_ERR>> for Error<I2C_ERR> {
+    fn from(err: InitError<I2C_ERR>) -> Self {
+        err.into_error()
+    }
 }
 
 impl<I2C_ERR> From<I2C_ERR> for Error<I2C_ERR> {
@@ -198,9 +690,46 @@ where
         match self {
             Error::I2cError(e) => defmt::write!(fmt, "I2C error: {:?}", e),
             Error::InterruptPinError => defmt::write!(fmt, "Interrupt pin not found"),
-            Error::RowOutOfRange => defmt::write!(fmt, "Row out of range"),
-            Error::ColumnOutOfRange => defmt::write!(fmt, "Column out of range"),
+            Error::RowOutOfRange { row, max } => {
+                defmt::write!(fmt, "Row {} out of range, max {}", row, max)
+            }
+            Error::ColumnOutOfRange { col, max } => {
+                defmt::write!(fmt, "Column {} out of range, max {}", col, max)
+            }
+            Error::InvalidCustomCharLocation { location, max } => {
+                defmt::write!(
+                    fmt,
+                    "Invalid custom character location {}, max {}",
+                    location,
+                    max
+                )
+            }
+            Error::DeviceNotFound { address } => {
+                defmt::write!(fmt, "No device found at address {=u8:#04x}", address)
+            }
+            Error::InvalidGeometry { rows, cols } => {
+                defmt::write!(fmt, "Invalid display geometry {}x{}", cols, rows)
+            }
             Error::FormattingError => defmt::write!(fmt, "Formatting error"),
+            Error::NotInitialized => defmt::write!(fmt, "LCD not initialized"),
+            #[cfg(feature = "verify")]
+            Error::VerificationFailed => defmt::write!(fmt, "Register verification failed"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<I2C_ERR> defmt::Format for InitError<I2C_ERR>
+where
+    I2C_ERR: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            InitError::PendingUpdate(e) => defmt::write!(fmt, "Pending update failed: {}", e),
+            InitError::ExpanderSetup(e) => defmt::write!(fmt, "Expander setup failed: {}", e),
+            InitError::PowerOnDelay(e) => defmt::write!(fmt, "Power on delay failed: {}", e),
+            InitError::FourBitMode(e) => defmt::write!(fmt, "4 bit mode entry failed: {}", e),
+            InitError::Clear(e) => defmt::write!(fmt, "Clearing the display failed: {}", e),
         }
     }
 }
@@ -210,25 +739,108 @@ where
     I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
     D: DelayMs<u16> + DelayUs<u16>,
 {
-    /// Create a new LCD backpack with the default I2C address of 0x20
+    /// Create a new LCD backpack with the default I2C address of 0x20. A `Custom` geometry outside of 1-4 rows and 1-40
+    /// columns is clamped into that range; use `try_new` to have it rejected instead.
     pub fn new(lcd_type: LcdDisplayType, i2c: I2C, delay: D) -> Self {
-        Self::new_with_address(lcd_type, i2c, delay, 0x20)
+        Self::new_with_address(lcd_type, i2c, delay, DEFAULT_I2C_ADDRESS)
     }
 
-    /// Create a new LCD backpack with the specified I2C address
+    /// Create a new LCD backpack with the specified I2C address. A `Custom` geometry is clamped as in `new`.
     pub fn new_with_address(lcd_type: LcdDisplayType, i2c: I2C, delay: D, address: u8) -> Self {
-        let register = match Mcp230xx::<I2C, Mcp23008>::new(i2c, address) {
-            Ok(r) => r,
-            Err(_) => panic!("Could not create MCP23008"),
-        };
+        Self::from_parts(lcd_type, i2c, address, delay)
+    }
 
+    /// Create a new LCD backpack with the default I2C address of 0x20, returning an error if the MCP23008 does not
+    /// respond on the I2C bus
+    pub fn try_new(lcd_type: LcdDisplayType, i2c: I2C, delay: D) -> Result<Self, Error<I2C_ERR>> {
+        Self::try_new_with_address(lcd_type, i2c, delay, DEFAULT_I2C_ADDRESS)
+    }
+
+    /// Create a new LCD backpack with the specified I2C address, returning an error if the `Custom` geometry is invalid
+    /// or the MCP23008 does not respond on the I2C bus
+    pub fn try_new_with_address(
+        lcd_type: LcdDisplayType,
+        i2c: I2C,
+        delay: D,
+        address: u8,
+    ) -> Result<Self, Error<I2C_ERR>> {
+        if !lcd_type.is_valid() {
+            if let LcdDisplayType::Custom { rows, cols, .. } = lcd_type {
+                return Err(Error::InvalidGeometry { rows, cols });
+            }
+        }
+        let mut lcd = Self::from_parts(lcd_type, i2c, address, delay);
+
+        // probe the MCP23008 so a missing or unwired backpack is reported here rather than during init
+        if lcd.read_register(Register::IODIR).is_err() {
+            return Err(Error::DeviceNotFound { address });
+        }
+
+        Ok(lcd)
+    }
+
+    /// Create a new LCD backpack with the default I2C address of 0x20 and initialize the LCD
+    pub fn new_init(lcd_type: LcdDisplayType, i2c: I2C, delay: D) -> Result<Self, Error<I2C_ERR>> {
+        Self::new_init_with_address(lcd_type, i2c, delay, DEFAULT_I2C_ADDRESS)
+    }
+
+    /// Create a new LCD backpack with the specified I2C address and initialize the LCD
+    pub fn new_init_with_address(
+        lcd_type: LcdDisplayType,
+        i2c: I2C,
+        delay: D,
+        address: u8,
+    ) -> Result<Self, Error<I2C_ERR>> {
+        let mut lcd = Self::try_new_with_address(lcd_type, i2c, delay, address)?;
+        lcd.init()?;
+        Ok(lcd)
+    }
+
+    /// Create a new LCD backpack from its parts without communicating with the MCP23008
+    fn from_parts(lcd_type: LcdDisplayType, i2c: I2C, address: u8, delay: D) -> Self {
         Self {
-            register,
+            i2c,
+            address,
             delay,
+            display_function: LCD_FLAG_4BITMODE | LCD_FLAG_5x8_DOTS | lcd_type.line_mode_flag(),
             lcd_type,
-            display_function: LCD_FLAG_4BITMODE | LCD_FLAG_5x8_DOTS | LCD_FLAG_2LINE,
+            pins: PinMapping::default(),
+            timing: TimingProfile::default(),
+            backlight: true,
             display_control: LCD_FLAG_DISPLAYON | LCD_FLAG_CURSOROFF | LCD_FLAG_BLINKOFF,
             display_mode: LCD_FLAG_ENTRYLEFT | LCD_FLAG_ENTRYSHIFTDECREMENT,
+            cursor_col: 0,
+            cursor_row: 0,
+            cgram_addressed: false,
+            custom_chars: [None; 8],
+            custom_char_last_used: [0; 8],
+            custom_char_clock: 0,
+            overflow_policy: OverflowPolicy::Unchecked,
+            tab_width: DEFAULT_TAB_WIDTH,
+            terminal_mode: false,
+            ansi: AnsiParser::new(),
+            idle_timeout_ms: None,
+            idle_display_off: false,
+            idle_ms: 0,
+            asleep: false,
+            character_mapper: None,
+            transliteration: false,
+            wrap_ellipsis: false,
+            replacement_char: DEFAULT_REPLACEMENT_CHAR,
+            scroll_offset: 0,
+            updating: false,
+            update_buffer: [0; UPDATE_BUFFER_LEN],
+            update_len: 0,
+            output_latch: None,
+            shadow: Shadow::new(),
+            watchdog_feed: None,
+            reinit_after_errors: None,
+            consecutive_errors: 0,
+            reinit_pending: false,
+            initialized: false,
+            last_error: None,
+            #[cfg(feature = "stats")]
+            stats: LcdStats::default(),
         }
     }
 
@@ -237,46 +849,174 @@ where
         &mut self.delay
     }
 
+    /// Consume the LCD backpack and return the I2C bus and delay objects, so they can be used by other drivers
+    pub fn release(self) -> (I2C, D) {
+        (self.i2c, self.delay)
+    }
+
+    /// Get the number of rows of the display
+    pub fn rows(&self) -> u8 {
+        self.lcd_type.rows()
+    }
+
+    /// Get the number of columns of the display
+    pub fn cols(&self) -> u8 {
+        self.lcd_type.cols()
+    }
+
+    /// Get the type of the display
+    pub fn display_type(&self) -> LcdDisplayType {
+        self.lcd_type
+    }
+
+    /// Get the I2C traffic and LCD operation counts
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> LcdStats {
+        self.stats
+    }
+
+    /// Reset the I2C traffic and LCD operation counts to zero
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&mut self) {
+        self.stats = LcdStats::default();
+    }
+
+    /// Get the delays used when driving the LCD
+    pub fn timing(&self) -> TimingProfile {
+        self.timing
+    }
+
+    /// Set the delays used when driving the LCD. The new delays apply from the next write, and the power on delay from
+    /// the next call to `init()`.
+    pub fn set_timing(&mut self, timing: TimingProfile) {
+        self.timing = timing;
+    }
+
+    /// Set a function that feeds a hardware watchdog. It is called before every internal wait and once per
+    /// millisecond during longer waits, such as those in `init()` and `clear()`.
+    pub fn set_watchdog_feed(&mut self, feed: Option<fn()>) {
+        self.watchdog_feed = feed;
+    }
+
+    /// Enable automatic re-initialization after `errors` consecutive I2C errors, such as when the display browned out.
+    /// The next command or data write then runs the init sequence again and restores the display flags, the custom
+    /// characters, the scroll position and the cursor. With the `shadow` feature the display contents known to the
+    /// driver are restored too; without it the display is left cleared. Pass `None` to disable it.
+    pub fn set_auto_reinit(&mut self, errors: Option<u8>) {
+        self.reinit_after_errors = errors;
+        self.consecutive_errors = 0;
+        self.reinit_pending = false;
+    }
+
     /// Initialize the LCD. Must be called before any other methods. Will turn on the blanked display, with no cursor or blinking.
-    pub fn init(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
-        // set up back light
-        self.register
-            .set_direction(BACKLIGHT_PIN, Direction::Output)?;
-        self.register.set_gpio(BACKLIGHT_PIN, Level::High)?;
+    /// A failure is reported as an [`InitError`] naming the step that failed.
+    pub fn init(&mut self) -> Result<&mut Self, InitError<I2C_ERR>> {
+        self.reinit_pending = false;
 
-        // set data pins to output
-        for pin in DATA_PINS.iter() {
-            self.register.set_direction(*pin, Direction::Output)?;
+        // initialization relies on its delays, so any update in progress is ended first
+        self.end_update().map_err(InitError::PendingUpdate)?;
+        self.configure_expander()
+            .map_err(InitError::ExpanderSetup)?;
+
+        // need to wait 40ms after power rises above 2.7V before sending any commands
+        let power_on_delay_ms = self.timing.power_on_delay_ms;
+        if power_on_delay_ms > 0 {
+            self.wait_ms(power_on_delay_ms)
+                .map_err(InitError::PowerOnDelay)?;
         }
 
-        // RS & Enable piun
-        self.register.set_direction(RS_PIN, Direction::Output)?;
-        self.register.set_direction(ENABLE_PIN, Direction::Output)?;
+        // the LCD accepts commands from here on, unless entering 4 bit mode or clearing fails
+        self.initialized = true;
+        if let Err(error) = self.enter_4_bit_mode() {
+            self.initialized = false;
+            return Err(InitError::FourBitMode(error));
+        }
+        // clearing also returns the cursor home
+        if let Err(error) = self.clear() {
+            self.initialized = false;
+            return Err(InitError::Clear(error));
+        }
+
+        Ok(self)
+    }
+
+    /// Reset the LCD's interface by running the 4 bit mode entry sequence again and resending the display flags,
+    /// without clearing the display. This recovers an LCD that lost nibble synchronization, for example after a glitch
+    /// in the middle of a transfer. The cursor is moved back to its tracked position. Returns `Error::NotInitialized`
+    /// before `init()` or `attach()` has succeeded.
+    pub fn reset(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        if !self.initialized {
+            return Err(Error::NotInitialized);
+        }
+        self.end_update()?;
+        let (cursor_col, cursor_row) = self.get_cursor();
+        self.enter_4_bit_mode()?;
+        self.restore_cursor(cursor_col, cursor_row)?;
+        Ok(self)
+    }
 
-        // need to wait 40ms after power rises above 2.7V before sending any commands. wait alittle longer.
-        self.delay().delay_ms(50);
+    /// Attach to an LCD that was already initialized, such as after a soft reset of the microcontroller, without
+    /// clearing it. The backpack's pins are configured and the display flags are sent to the LCD, but the 4 bit mode
+    /// entry sequence is skipped so the display keeps its contents. The cursor is moved to the home position. Use
+    /// `init()` instead if the LCD may have lost power or nibble synchronization.
+    pub fn attach(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.reinit_pending = false;
+        self.end_update()?;
+        self.configure_expander()?;
+        self.set_pin_level(self.pins.rs, Level::Low)?;
+        self.set_pin_level(self.pins.enable, Level::Low)?;
+        self.initialized = true;
+        self.send_command(LCD_CMD_FUNCTIONSET | self.display_function)?;
+        self.send_command(LCD_CMD_DISPLAYCONTROL | self.display_control)?;
+        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+        // the display contents are unknown to the driver
+        self.shadow.forget();
+        self.set_cursor(0, 0)?;
+        Ok(self)
+    }
 
+    /// Put the LCD into 4 bit mode from any state and send the display flags
+    fn enter_4_bit_mode(&mut self) -> Result<(), Error<I2C_ERR>> {
         // pull RS & Enable low to start command. RW is hardwired low on backpack.
-        self.register.set_gpio(RS_PIN, Level::Low)?;
-        self.register.set_gpio(ENABLE_PIN, Level::Low)?;
+        self.set_pin_level(self.pins.rs, Level::Low)?;
+        self.set_pin_level(self.pins.enable, Level::Low)?;
 
         // Put LCD into 4 bit mode, device starts in 8 bit mode
         self.write_4_bits(0x03)?;
-        self.delay().delay_ms(5);
+        self.wait_us(INIT_FIRST_WAIT_US);
         self.write_4_bits(0x03)?;
-        self.delay().delay_ms(5);
+        self.wait_us(INIT_SECOND_WAIT_US);
         self.write_4_bits(0x03)?;
-        self.delay().delay_us(150);
         self.write_4_bits(0x02)?;
 
         // set up the display
         self.send_command(LCD_CMD_FUNCTIONSET | self.display_function)?;
         self.send_command(LCD_CMD_DISPLAYCONTROL | self.display_control)?;
         self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
-        self.clear()?;
-        self.home()?;
+        Ok(())
+    }
 
-        Ok(self)
+    /// Configure the backpack's I/O expander pins for driving the LCD
+    fn configure_expander(&mut self) -> Result<(), Error<I2C_ERR>> {
+        // read the output latch from the device again in case it was changed while the driver was not in control
+        self.output_latch = None;
+
+        // keep the address pointer on the same register for multi-byte writes
+        self.write_register(Register::IOCON, IOCON_SEQOP)?;
+
+        // set up back light
+        self.set_pin_direction(self.pins.backlight, Direction::Output)?;
+        self.backlight(self.backlight)?;
+
+        // set data pins to output
+        for pin in self.pins.data {
+            self.set_pin_direction(pin, Direction::Output)?;
+        }
+
+        // RS & Enable piun
+        self.set_pin_direction(self.pins.rs, Direction::Output)?;
+        self.set_pin_direction(self.pins.enable, Direction::Output)?;
+        Ok(())
     }
 
     //--------------------------------------------------------------------------------------------------
@@ -286,29 +1026,114 @@ where
     /// Clear the display
     pub fn clear(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
         self.send_command(LCD_CMD_CLEARDISPLAY)?;
-        self.delay().delay_ms(2);
+        self.wait_ms(self.timing.clear_home_delay_ms)?;
+        self.reset_cursor_state();
         Ok(self)
     }
 
     /// Set the cursor to the home position
     pub fn home(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
         self.send_command(LCD_CMD_RETURNHOME)?;
-        self.delay().delay_ms(2);
+        self.wait_ms(self.timing.clear_home_delay_ms)?;
+        self.reset_cursor_state();
         Ok(self)
     }
 
+    /// Start clearing the display without waiting for the LCD to finish. `now_ms` is the current time in milliseconds,
+    /// and the returned token reports when the display is ready for the next command.
+    pub fn clear_nonblocking(&mut self, now_ms: u32) -> Result<PendingCommand, Error<I2C_ERR>> {
+        self.send_command(LCD_CMD_CLEARDISPLAY)?;
+        self.start_pending(now_ms)
+    }
+
+    /// Start moving the cursor to the home position without waiting for the LCD to finish. `now_ms` is the current
+    /// time in milliseconds, and the returned token reports when the display is ready for the next command.
+    pub fn home_nonblocking(&mut self, now_ms: u32) -> Result<PendingCommand, Error<I2C_ERR>> {
+        self.send_command(LCD_CMD_RETURNHOME)?;
+        self.start_pending(now_ms)
+    }
+
     /// Set the cursor position at specified column and row
     pub fn set_cursor(&mut self, col: u8, row: u8) -> Result<&mut Self, Error<I2C_ERR>> {
-        if row >= self.lcd_type.rows() {
-            return Err(Error::RowOutOfRange);
+        self.check_rows(row, 1)?;
+        self.check_cols(col, 1)?;
+
+        self.send_command(LCD_CMD_SETDDRAMADDR | self.lcd_type.ddram_address(col, row))?;
+        self.cursor_col = col;
+        self.cursor_row = row;
+        self.cgram_addressed = false;
+        Ok(self)
+    }
+
+    /// Clear a single row by writing spaces across it. The cursor is restored to its previous position afterwards.
+    pub fn clear_row(&mut self, row: u8) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.check_rows(row, 1)?;
+        self.fill_rows(row..row + 1, ' ')
+    }
+
+    /// Fill the whole display with a character. Custom characters can be used by passing their location as a
+    /// character, e.g. `'\x01'`. The cursor is restored to its previous position afterwards.
+    pub fn fill(&mut self, c: char) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.fill_rows(0..self.lcd_type.rows(), c)
+    }
+
+    /// Fill a range of rows with a character. The cursor is restored to its previous position afterwards.
+    pub fn fill_rows(
+        &mut self,
+        rows: core::ops::Range<u8>,
+        c: char,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        let (cursor_col, cursor_row) = self.get_cursor();
+        let code = self.single_char_code(c);
+        for row in rows {
+            self.set_cursor(self.row_start_col(), row)?;
+            for _ in 0..self.lcd_type.cols() {
+                self.write_data(code)?;
+            }
         }
-        if col >= self.lcd_type.cols() {
-            return Err(Error::ColumnOutOfRange);
+        self.restore_cursor(cursor_col, cursor_row)?;
+        Ok(self)
+    }
+
+    /// Get the current cursor position as `(col, row)`. The position is tracked by the driver as the backpack cannot read
+    /// it back from the LCD. The column may be past the last visible column when text has run past the end of the row.
+    pub fn get_cursor(&self) -> (u8, u8) {
+        (self.cursor_col, self.cursor_row)
+    }
+
+    /// Move the cursor one column to the left without changing the display contents. The cursor stays in the first
+    /// column, and a cursor past the end of the row moves to the last column.
+    pub fn move_cursor_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        if self.cursor_col == 0 {
+            return Ok(self);
+        }
+        let col = (self.cursor_col - 1).min(self.lcd_type.cols() - 1);
+        if col != self.cursor_col - 1 || self.lcd_type.split_column() == Some(self.cursor_col) {
+            self.send_command(
+                LCD_CMD_SETDDRAMADDR | self.lcd_type.ddram_address(col, self.cursor_row),
+            )?;
+        } else {
+            self.send_command(LCD_CMD_CURSORSHIFT | LCD_FLAG_CURSORMOVE | LCD_FLAG_MOVELEFT)?;
         }
+        self.cursor_col = col;
+        Ok(self)
+    }
 
-        self.send_command(
-            LCD_CMD_SETDDRAMADDR | (col + self.lcd_type.row_offsets()[row as usize]),
-        )?;
+    /// Move the cursor one column to the right without changing the display contents. The cursor stays in the last
+    /// column.
+    pub fn move_cursor_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        let col = self.cursor_col.saturating_add(1);
+        if col >= self.lcd_type.cols() {
+            return Ok(self);
+        }
+        if self.lcd_type.split_column() == Some(col) {
+            self.send_command(
+                LCD_CMD_SETDDRAMADDR | self.lcd_type.ddram_address(col, self.cursor_row),
+            )?;
+        } else {
+            self.send_command(LCD_CMD_CURSORSHIFT | LCD_FLAG_CURSORMOVE | LCD_FLAG_MOVERIGHT)?;
+        }
+        self.cursor_col = col;
         Ok(self)
     }
 
@@ -345,75 +1170,840 @@ where
         Ok(self)
     }
 
-    /// Scroll the display to the left
-    pub fn scroll_display_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
-        self.send_command(LCD_CMD_CURSORSHIFT | LCD_FLAG_DISPLAYMOVE | LCD_FLAG_MOVELEFT)?;
-        Ok(self)
+    /// Returns `true` if the display is on. An inactivity timeout turning the display off does not change this state.
+    pub fn is_display_on(&self) -> bool {
+        self.display_control & LCD_FLAG_DISPLAYON != 0
     }
 
-    /// Scroll the display to the right
-    pub fn scroll_display_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
-        self.send_command(LCD_CMD_CURSORSHIFT | LCD_FLAG_DISPLAYMOVE | LCD_FLAG_MOVERIGHT)?;
-        Ok(self)
+    /// Returns `true` if the cursor is visible
+    pub fn is_cursor_visible(&self) -> bool {
+        self.display_control & LCD_FLAG_CURSORON != 0
     }
 
-    /// Set the text flow direction to left to right
-    pub fn left_to_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
-        self.display_mode |= LCD_FLAG_ENTRYLEFT;
-        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
-        Ok(self)
+    /// Returns `true` if the cursor is blinking
+    pub fn is_blinking(&self) -> bool {
+        self.display_control & LCD_FLAG_BLINKON != 0
     }
 
-    /// Set the text flow direction to right to left
-    pub fn right_to_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
-        self.display_mode &= !LCD_FLAG_ENTRYLEFT;
-        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+    /// Turn the backlight on or off
+    pub fn backlight(&mut self, on: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.wake()?;
+        self.set_backlight_pin(on)?;
+        self.backlight = on;
         Ok(self)
     }
 
-    /// Set the auto scroll mode
-    pub fn autoscroll(&mut self, autoscroll: bool) -> Result<&mut Self, Error<I2C_ERR>> {
-        if autoscroll {
-            self.display_mode |= LCD_FLAG_ENTRYSHIFTINCREMENT;
-        } else {
-            self.display_mode &= !LCD_FLAG_ENTRYSHIFTINCREMENT;
+    /// Flash the backlight the specified number of times as a visual alert. Each flash inverts the backlight for half
+    /// of `period_ms` and then restores it for the other half, so the backlight is left in its original state.
+    pub fn flash_backlight(
+        &mut self,
+        times: u8,
+        period_ms: u16,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        let original = self.backlight;
+        for _ in 0..times {
+            self.backlight(!original)?;
+            self.wait_ms(period_ms / 2)?;
+            self.backlight(original)?;
+            self.wait_ms(period_ms - period_ms / 2)?;
         }
-        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
         Ok(self)
     }
 
-    /// Create a new custom character
-    pub fn create_char(
+    /// Flash the whole screen the specified number of times to draw attention to an alarm. Each flash turns the display,
+    /// and with `with_backlight` also the backlight, off for half of `period_ms` and back on for the other half. The
+    /// display contents are kept, and the display and backlight are left in their original state.
+    pub fn flash_screen(
         &mut self,
-        location: u8,
-        charmap: [u8; 8],
+        times: u8,
+        period_ms: u16,
+        with_backlight: bool,
     ) -> Result<&mut Self, Error<I2C_ERR>> {
-        self.send_command(LCD_CMD_SETCGRAMADDR | ((location & 0x7) << 3))?;
-        for &charmap_byte in charmap.iter() {
-            self.write_data(charmap_byte)?;
+        let display_on = self.is_display_on();
+        let backlight_on = self.backlight;
+        for _ in 0..times {
+            self.show_display(false)?;
+            if with_backlight {
+                self.backlight(false)?;
+            }
+            self.wait_ms(period_ms / 2)?;
+            self.show_display(true)?;
+            if with_backlight {
+                self.backlight(true)?;
+            }
+            self.wait_ms(period_ms - period_ms / 2)?;
+        }
+        self.show_display(display_on)?.backlight(backlight_on)
+    }
+
+    /// Returns `true` if the backlight is on
+    pub fn is_backlight_on(&self) -> bool {
+        self.backlight
+    }
+
+    /// Set the inactivity timeout. After `timeout_ms` milliseconds without any writes, as measured by calls to `tick()`,
+    /// the backlight is turned off, and the display too if `display_off` is `true`. The next write or a call to `wake()`
+    /// restores them. Pass `None` to disable the timeout.
+    pub fn set_idle_timeout(&mut self, timeout_ms: Option<u32>, display_off: bool) {
+        self.idle_timeout_ms = timeout_ms;
+        self.idle_display_off = display_off;
+        self.idle_ms = 0;
+    }
+
+    /// Advance the inactivity timer by `elapsed_ms` milliseconds, turning the backlight off if the inactivity timeout has
+    /// been reached. Call this periodically from the application's main loop or a timer.
+    pub fn tick(&mut self, elapsed_ms: u32) -> Result<&mut Self, Error<I2C_ERR>> {
+        if let Some(timeout_ms) = self.idle_timeout_ms {
+            if !self.asleep {
+                self.idle_ms = self.idle_ms.saturating_add(elapsed_ms);
+                if self.idle_ms >= timeout_ms {
+                    if self.idle_display_off {
+                        self.send_command(
+                            LCD_CMD_DISPLAYCONTROL | (self.display_control & !LCD_FLAG_DISPLAYON),
+                        )?;
+                    }
+                    self.set_backlight_pin(false)?;
+                    self.asleep = true;
+                }
+            }
         }
         Ok(self)
     }
 
-    /// Prints a string to the LCD at the current cursor position
-    pub fn print(&mut self, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
-        for c in text.chars() {
-            self.write_data(c as u8)?;
+    /// Reset the inactivity timer, restoring the backlight and display if the inactivity timeout turned them off
+    pub fn wake(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.idle_ms = 0;
+        if self.asleep {
+            self.asleep = false;
+            self.set_backlight_pin(self.backlight)?;
+            if self.idle_display_off {
+                self.send_command(LCD_CMD_DISPLAYCONTROL | self.display_control)?;
+            }
         }
         Ok(self)
     }
 
-    //--------------------------------------------------------------------------------------------------
-    // Internal data writing functions
-    //--------------------------------------------------------------------------------------------------
+    /// Returns `true` if the inactivity timeout has turned the backlight off
+    pub fn is_asleep(&self) -> bool {
+        self.asleep
+    }
 
-    /// Write 4 bits to the LCD
-    fn write_4_bits(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
+    /// Start queuing the writes of a sequence of cursor moves and prints. The queued writes are sent in as few I2C
+    /// transactions as possible when `end_update()` is called, or earlier when the queue fills up or a command such as
+    /// `clear()` has to wait for the LCD. While updating, the per-nibble delays are skipped and the I2C transfer time
+    /// paces the LCD, which is sufficient at bus speeds up to 400 kHz. Calling `begin_update()` again while updating
+    /// has no effect. Requires the MCP23008 to have been set up by `init()`.
+    pub fn begin_update(&mut self) -> &mut Self {
+        self.updating = true;
+        self
+    }
+
+    /// Send the writes queued since `begin_update()` and return to writing immediately
+    pub fn end_update(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.updating = false;
+        self.flush_update()?;
+        Ok(self)
+    }
+
+    /// Scroll the display to the left
+    pub fn scroll_display_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.send_command(LCD_CMD_CURSORSHIFT | LCD_FLAG_DISPLAYMOVE | LCD_FLAG_MOVELEFT)?;
+        self.track_display_shift(true);
+        Ok(self)
+    }
+
+    /// Scroll the display to the right
+    pub fn scroll_display_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.send_command(LCD_CMD_CURSORSHIFT | LCD_FLAG_DISPLAYMOVE | LCD_FLAG_MOVERIGHT)?;
+        self.track_display_shift(false);
+        Ok(self)
+    }
+
+    /// Get the net number of positions the display has been scrolled, counting scrolls to the left as positive and
+    /// scrolls to the right as negative. Scrolling by auto scroll mode is included. `clear()` and `home()` reset it to 0.
+    pub fn scroll_offset(&self) -> i8 {
+        let line_length = self.ddram_line_length();
+        if self.scroll_offset > line_length / 2 {
+            (self.scroll_offset as i16 - line_length as i16) as i8
+        } else {
+            self.scroll_offset as i8
+        }
+    }
+
+    /// Scroll the display back to its unscrolled position with the fewest shifts, without moving the cursor or changing
+    /// the display contents
+    pub fn reset_scroll(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        let offset = self.scroll_offset();
+        for _ in 0..offset.unsigned_abs() {
+            if offset > 0 {
+                self.scroll_display_right()?;
+            } else {
+                self.scroll_display_left()?;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Set the text flow direction to left to right
+    pub fn left_to_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.display_mode |= LCD_FLAG_ENTRYLEFT;
+        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+        Ok(self)
+    }
+
+    /// Set the text flow direction to right to left
+    pub fn right_to_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.display_mode &= !LCD_FLAG_ENTRYLEFT;
+        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+        Ok(self)
+    }
+
+    /// Set the auto scroll mode
+    pub fn autoscroll(&mut self, autoscroll: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        if autoscroll {
+            self.display_mode |= LCD_FLAG_ENTRYSHIFTINCREMENT;
+        } else {
+            self.display_mode &= !LCD_FLAG_ENTRYSHIFTINCREMENT;
+        }
+        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+        Ok(self)
+    }
+
+    /// Returns `true` if auto scroll mode is enabled
+    pub fn is_autoscroll(&self) -> bool {
+        self.display_mode & LCD_FLAG_ENTRYSHIFTINCREMENT != 0
+    }
+
+    /// Returns `true` if text flows left to right
+    pub fn is_left_to_right(&self) -> bool {
+        self.display_mode & LCD_FLAG_ENTRYLEFT != 0
+    }
+
+    /// Set the controller's line mode. Disabling 2 line mode puts the controller into 1 line mode, which is required by
+    /// some single row displays and by the 5x10 dot font.
+    pub fn two_line_mode(&mut self, two_line: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        if two_line {
+            self.display_function |= LCD_FLAG_2LINE;
+        } else {
+            self.display_function &= !LCD_FLAG_2LINE;
+        }
+        self.send_command(LCD_CMD_FUNCTIONSET | self.display_function)?;
+        self.shadow.forget();
+        Ok(self)
+    }
+
+    /// Set the 5x10 dot font. The HD44780 only supports the 5x10 dot font in 1 line mode, see `two_line_mode()`.
+    pub fn font_5x10(&mut self, font_5x10: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        if font_5x10 {
+            self.display_function |= LCD_FLAG_5x10_DOTS;
+        } else {
+            self.display_function &= !LCD_FLAG_5x10_DOTS;
+        }
+        self.send_command(LCD_CMD_FUNCTIONSET | self.display_function)?;
+        Ok(self)
+    }
+
+    /// Set double height mode. This uses the extended instruction set of SSD1803A/US2066 compatible controllers and
+    /// has no useful effect on a standard HD44780. Pass `None` to return to normal height rows. On compatible
+    /// controllers the double height flag replaces the 5x10 dot font flag, so `font_5x10()` should not be used
+    /// together with double height mode.
+    pub fn double_height(
+        &mut self,
+        mode: Option<DoubleHeightMode>,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        match mode {
+            Some(mode) => {
+                // select the extended instruction set to set the double height format
+                self.send_command(
+                    LCD_CMD_FUNCTIONSET
+                        | (self.display_function & (LCD_FLAG_8BITMODE | LCD_FLAG_2LINE))
+                        | LCD_FLAG_EXTENDED_REGISTER,
+                )?;
+                self.send_command(LCD_CMD_DOUBLEHEIGHTFORMAT | mode as u8)?;
+                self.display_function |= LCD_FLAG_DOUBLEHEIGHT;
+            }
+            None => {
+                self.display_function &= !LCD_FLAG_DOUBLEHEIGHT;
+            }
+        }
+        // return to the standard instruction set with the double height flag updated
+        self.send_command(LCD_CMD_FUNCTIONSET | self.display_function)?;
+        Ok(self)
+    }
+
+    /// Create a new custom character at a CGRAM location in the range 0-7. Returns a handle that is used to print the custom character with
+    /// `write_custom_char()`.
+    pub fn create_char(
+        &mut self,
+        location: u8,
+        charmap: [u8; 8],
+    ) -> Result<CustomChar, Error<I2C_ERR>> {
+        if location > 7 {
+            return Err(Error::InvalidCustomCharLocation { location, max: 7 });
+        }
+        self.send_command(LCD_CMD_SETCGRAMADDR | (location << 3))?;
+        self.cgram_addressed = true;
+        for &charmap_byte in charmap.iter() {
+            self.write_data(charmap_byte)?;
+        }
+        self.custom_chars[location as usize] = Some(charmap);
+        self.touch_custom_char(location);
+        Ok(CustomChar { location })
+    }
+
+    /// Create a new 5x10 dot custom character in a CGRAM slot in the range 0-3, for use with the 5x10 font enabled by
+    /// `font_5x10()`. Each slot occupies two of the 5x8 CGRAM locations, so slot `n` replaces locations `2n` and
+    /// `2n + 1`. Returns a handle that is used to print the custom character with `write_custom_char()`.
+    pub fn create_char_5x10(
+        &mut self,
+        slot: u8,
+        charmap: [u8; 10],
+    ) -> Result<CustomChar, Error<I2C_ERR>> {
+        if slot > 3 {
+            return Err(Error::InvalidCustomCharLocation {
+                location: slot,
+                max: 3,
+            });
+        }
+        self.send_command(LCD_CMD_SETCGRAMADDR | (slot << 4))?;
+        self.cgram_addressed = true;
+        for &charmap_byte in charmap.iter() {
+            self.write_data(charmap_byte)?;
+        }
+        // keep the cached CGRAM contents of both 5x8 locations in step with what was written
+        let location = slot << 1;
+        let mut lower = [0; 8];
+        lower.copy_from_slice(&charmap[..8]);
+        let mut upper = self.custom_chars[location as usize + 1].unwrap_or([0; 8]);
+        upper[..2].copy_from_slice(&charmap[8..]);
+        self.custom_chars[location as usize] = Some(lower);
+        self.custom_chars[location as usize + 1] = Some(upper);
+        self.touch_custom_char(location + 1);
+        self.touch_custom_char(location);
+        Ok(CustomChar { location })
+    }
+
+    /// Get a custom character for a glyph, programming it into CGRAM if needed. If the glyph is already loaded, its
+    /// existing location is returned. Otherwise the glyph is programmed into an unused location or, if all 8 locations
+    /// are in use, the least recently used one. This lets more than 8 distinct glyphs be used over time without manual
+    /// location bookkeeping. Note that evicting a location changes any of its characters still shown on the display.
+    pub fn load_glyph(&mut self, charmap: [u8; 8]) -> Result<CustomChar, Error<I2C_ERR>> {
+        if let Some(location) = self.custom_chars.iter().position(|c| *c == Some(charmap)) {
+            let location = location as u8;
+            self.touch_custom_char(location);
+            return Ok(CustomChar { location });
+        }
+        let location = match self.custom_chars.iter().position(|c| c.is_none()) {
+            Some(location) => location,
+            None => (0..self.custom_chars.len())
+                .min_by_key(|&location| self.custom_char_last_used[location])
+                .unwrap_or(0),
+        };
+        self.create_char(location as u8, charmap)
+    }
+
+    /// Load up to 8 glyphs at once with `load_glyph()`, such as those in the [`glyphs`] module. Returns the custom
+    /// characters in the same order as the glyphs.
+    pub fn load_glyphs<const N: usize>(
+        &mut self,
+        charmaps: [[u8; 8]; N],
+    ) -> Result<[CustomChar; N], Error<I2C_ERR>> {
+        if N > self.custom_chars.len() {
+            return Err(Error::InvalidCustomCharLocation {
+                location: (N - 1).min(u8::MAX as usize) as u8,
+                max: self.custom_chars.len() as u8 - 1,
+            });
+        }
+        let mut custom_chars = [CustomChar { location: 0 }; N];
+        for (custom_char, charmap) in custom_chars.iter_mut().zip(charmaps) {
+            *custom_char = self.load_glyph(charmap)?;
+        }
+        Ok(custom_chars)
+    }
+
+    /// Get the character map last programmed at a CGRAM location with `create_char()`. The backpack cannot read CGRAM
+    /// back from the LCD, so this returns the driver's copy. Returns `None` if nothing has been programmed there.
+    pub fn get_custom_char(&self, location: u8) -> Option<[u8; 8]> {
+        self.custom_chars.get(location as usize).copied().flatten()
+    }
+
+    /// Returns `true` if a custom character has been programmed at the CGRAM location
+    pub fn is_custom_char_in_use(&self, location: u8) -> bool {
+        self.get_custom_char(location).is_some()
+    }
+
+    /// Write a custom character created with `create_char()` at the current cursor position
+    pub fn write_custom_char(
+        &mut self,
+        custom_char: CustomChar,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.touch_custom_char(custom_char.location);
+        self.print_byte(custom_char.location)?;
+        Ok(self)
+    }
+
+    /// Prints a string to the LCD at the current cursor position. Tab characters advance the cursor to the next tab
+    /// stop by writing spaces. In terminal mode, line breaks and a subset of ANSI escape sequences are interpreted too.
+    pub fn print(&mut self, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.print_iter(text.chars())
+    }
+
+    /// Prints characters from an iterator to the LCD at the current cursor position, handling them the same way as
+    /// `print()`. This allows generated text to be printed without first collecting it into a buffer.
+    pub fn print_iter(
+        &mut self,
+        chars: impl IntoIterator<Item = char>,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        for c in chars {
+            if !self.terminal_mode {
+                self.print_text_char(c)?;
+                continue;
+            }
+            match self.ansi.feed(c) {
+                AnsiAction::Print(c) => self.print_text_char(c)?,
+                AnsiAction::NewLine => self.wrap_to_next_row()?,
+                AnsiAction::CarriageReturn => {
+                    self.set_cursor(self.row_start_col(), self.cursor_row)?;
+                }
+                AnsiAction::CursorPosition { row, col } => {
+                    let row = row.min(self.lcd_type.rows() - 1);
+                    let col = col.min(self.lcd_type.cols() - 1);
+                    self.set_cursor(col, row)?;
+                }
+                AnsiAction::EraseDisplay(mode) => self.erase_display(mode)?,
+                AnsiAction::EraseLine(mode) => self.erase_line(mode)?,
+                AnsiAction::None => {}
+            }
+        }
+        Ok(self)
+    }
+
+    /// Prints a string with Japanese katakana to an LCD with the A00 character ROM. Full-width and half-width katakana
+    /// and Japanese punctuation are converted to the ROM's katakana codes, with voiced katakana such as `ガ` printed as
+    /// two characters. Other characters are printed as with `print()`, but without terminal mode handling.
+    #[cfg(feature = "charset-a00")]
+    pub fn print_katakana(&mut self, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
+        for c in text.chars() {
+            match charset::katakana_to_a00(c) {
+                Some((code, mark)) => {
+                    self.print_byte(code)?;
+                    if let Some(mark) = mark {
+                        self.print_byte(mark)?;
+                    }
+                }
+                None => self.print_text_char(c)?,
+            }
+        }
+        Ok(self)
+    }
+
+    /// Prints an unsigned number in decimal. Numbers are converted without `core::fmt`, which keeps the code size
+    /// small on targets where the formatting machinery does not fit.
+    pub fn print_u32(&mut self, value: u32) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.print_number(&NumberBuffer::unsigned(value, 10))?;
+        Ok(self)
+    }
+
+    /// Prints a signed number in decimal, without `core::fmt`
+    pub fn print_i32(&mut self, value: i32) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.print_number(&NumberBuffer::signed(value))?;
+        Ok(self)
+    }
+
+    /// Prints a number in upper case hexadecimal without a prefix or leading zeros, without `core::fmt`
+    pub fn print_hex(&mut self, value: u32) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.print_number(&NumberBuffer::unsigned(value, 16))?;
+        Ok(self)
+    }
+
+    /// Prints a number in binary without a prefix or leading zeros, without `core::fmt`
+    pub fn print_bin(&mut self, value: u32) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.print_number(&NumberBuffer::unsigned(value, 2))?;
+        Ok(self)
+    }
+
+    /// Prints a fixed point number in decimal with `decimals` digits after the decimal point, without `core::fmt`. For
+    /// example, a sensor reading of 2347 hundredths is printed as `23.47` with `print_fixed(2347, 2)`. At most 9 decimals
+    /// are printed.
+    pub fn print_fixed(&mut self, value: i32, decimals: u8) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.print_number(&NumberBuffer::fixed(value, decimals))?;
+        Ok(self)
+    }
+
+    /// Prints an unsigned number in decimal, right aligned in a field of `width` characters. Padding to a fixed width
+    /// overwrites the digits of a previously printed wider value, such as when a value drops from 100 to 99. Numbers
+    /// wider than the field are printed in full.
+    pub fn print_u32_padded(
+        &mut self,
+        value: u32,
+        width: u8,
+        padding: Padding,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        let mut number = NumberBuffer::unsigned(value, 10);
+        number.pad(width, padding);
+        self.print_number(&number)?;
+        Ok(self)
+    }
+
+    /// Prints a signed number in decimal, right aligned in a field of `width` characters
+    pub fn print_i32_padded(
+        &mut self,
+        value: i32,
+        width: u8,
+        padding: Padding,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        let mut number = NumberBuffer::signed(value);
+        number.pad(width, padding);
+        self.print_number(&number)?;
+        Ok(self)
+    }
+
+    /// Prints a fixed point number as with `print_fixed()`, right aligned in a field of `width` characters
+    pub fn print_fixed_padded(
+        &mut self,
+        value: i32,
+        decimals: u8,
+        width: u8,
+        padding: Padding,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        let mut number = NumberBuffer::fixed(value, decimals);
+        number.pad(width, padding);
+        self.print_number(&number)?;
+        Ok(self)
+    }
+
+    /// Prints a floating point number rounded to `decimals` digits after the decimal point, without the float
+    /// formatting of `core::fmt`. The value is converted with `print_fixed()`, so the scaled value must fit in an `i32`;
+    /// larger values are clamped. Non-finite values are printed as `NaN`, `inf` or `-inf`.
+    #[cfg(feature = "float")]
+    pub fn print_f32(&mut self, value: f32, decimals: u8) -> Result<&mut Self, Error<I2C_ERR>> {
+        if value.is_nan() {
+            return self.print("NaN");
+        }
+        if value.is_infinite() {
+            return self.print(if value < 0.0 { "-inf" } else { "inf" });
+        }
+        let decimals = decimals.min(number::MAX_DECIMALS);
+        self.print_fixed(number::f32_to_fixed(value, decimals), decimals)
+    }
+
+    /// Enable or disable terminal mode. In terminal mode `print()` and the `core::fmt::Write` implementation move the
+    /// cursor to the start of the next row on `\n` and to the start of the current row on `\r`, and interpret these
+    /// ANSI escape sequences:
+    /// - `ESC[r;cH` and `ESC[r;cf` move the cursor to the one based row `r` and column `c`
+    /// - `ESC[nJ` erases from the cursor to the end of the screen (`n` = 0), from the start of the screen to the cursor
+    ///   (`n` = 1), or the whole screen (`n` = 2)
+    /// - `ESC[nK` erases from the cursor to the end of the row (`n` = 0), from the start of the row to the cursor
+    ///   (`n` = 1), or the whole row (`n` = 2)
+    ///
+    /// Other escape sequences are dropped. Erasing assumes left to right text flow.
+    pub fn set_terminal_mode(&mut self, terminal_mode: bool) {
+        self.terminal_mode = terminal_mode;
+        self.ansi = AnsiParser::new();
+    }
+
+    /// Writes raw character codes to the LCD at the current cursor position. The bytes are sent verbatim, without tab or
+    /// terminal mode handling, so custom characters (0x00-0x07) and the ROM glyphs above 0x7F can be shown. The overflow
+    /// policy still applies.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<&mut Self, Error<I2C_ERR>> {
+        for &byte in bytes {
+            self.print_byte(byte)?;
+        }
+        Ok(self)
+    }
+
+    /// Set the character mapper used to convert printed characters to character codes. Without a mapper, ASCII
+    /// characters are sent as is. Characters that cannot be mapped are printed as the replacement character.
+    pub fn set_character_mapper(&mut self, mapper: Option<&'static dyn CharacterMapper>) {
+        self.character_mapper = mapper;
+    }
+
+    /// Enable or disable the ASCII transliteration fallback. When enabled, characters without a glyph on the display
+    /// are replaced with a close ASCII approximation, such as `e` for `é`, `ss` for `ß` and `'` for curly quotes.
+    /// Without a character mapper, every non-ASCII character is considered to have no glyph. Disabled by default.
+    pub fn set_transliteration(&mut self, transliteration: bool) {
+        self.transliteration = transliteration;
+    }
+
+    /// Set the character code printed for characters that cannot be mapped to the display, such as a custom character
+    /// location or the ROM's solid block (0xFF). The default is `?`.
+    pub fn set_replacement_char(&mut self, code: u8) {
+        self.replacement_char = code;
+    }
+
+    /// Set the distance between tab stops in columns. The default is 4 columns. A width of 0 is treated as 1.
+    pub fn set_tab_width(&mut self, width: u8) {
+        self.tab_width = width.max(1);
+    }
+
+    /// Set how `print()` handles text that runs past the end of the row
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+        self.overflow_policy = policy;
+    }
+
+    /// Get how `print()` handles text that runs past the end of the row
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Replace the contents of a row with a string. The string is truncated to the width of the display, and the rest
+    /// of the row is padded with spaces so no characters from previous writes are left behind. With the `shadow`
+    /// feature, characters the display already shows are skipped, leaving the cursor after the last character actually
+    /// written.
+    pub fn write_row(&mut self, row: u8, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.check_rows(row, 1)?;
+        let start = self.row_start_col();
+        let mut chars = text.chars();
+        for offset in 0..self.lcd_type.cols() {
+            let code = chars.next().map_or(b' ', |c| self.single_char_code(c));
+            self.write_cell(self.flow_col(start, offset), row, code)?;
+        }
+        Ok(self)
+    }
+
+    /// Print a string starting at the specified column and row. The string is truncated at the edge of the row. With the
+    /// `shadow` feature, characters the display already shows are skipped, leaving the cursor after the last character
+    /// actually written.
+    pub fn print_at(&mut self, col: u8, row: u8, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.check_rows(row, 1)?;
+        self.check_cols(col, 1)?;
+        let room = if self.display_mode & LCD_FLAG_ENTRYLEFT != 0 {
+            self.lcd_type.cols() - col
+        } else {
+            col + 1
+        };
+        for (offset, c) in (0..room).zip(text.chars()) {
+            let code = self.single_char_code(c);
+            self.write_cell(self.flow_col(col, offset), row, code)?;
+        }
+        Ok(self)
+    }
+
+    /// Print formatted text at the current cursor position, as created by the `format_args!` macro. Unlike the `write!`
+    /// macro, a failure returns the error that caused it, which is `Error::FormattingError` if a value being formatted
+    /// failed rather than the LCD.
+    ///
+    /// ```rust,ignore
+    /// lcd.print_fmt(format_args!("T={}C", temp))?;
+    /// ```
+    pub fn print_fmt(
+        &mut self,
+        args: core::fmt::Arguments<'_>,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        let mut writer = FmtWriter {
+            lcd: &mut *self,
+            error: None,
+        };
+        if core::fmt::write(&mut writer, args).is_err() {
+            return Err(writer.error.take().unwrap_or(Error::FormattingError));
+        }
+        Ok(self)
+    }
+
+    /// Take the error behind the most recent failed write through `core::fmt::Write`, such as with the `write!`
+    /// macro, which can only report that writing failed. The I2C error itself is not kept, so a bus failure is returned
+    /// as `Error::I2cError(())`; use `print_fmt()` to get it. Returns `None` if no write failed since the error was last
+    /// taken.
+    pub fn take_last_error(&mut self) -> Option<Error<()>> {
+        self.last_error.take()
+    }
+
+    /// Get a `core::fmt::Write` handle that writes from the start of a row and drops characters past its end, so
+    /// formatted text cannot spill onto other rows. Writing fails if the row is outside the display.
+    pub fn row_writer(&mut self, row: u8) -> RowWriter<'_, I2C, D> {
+        RowWriter::new(self, row)
+    }
+
+    //--------------------------------------------------------------------------------------------------
+    // Internal MCP23008 register access
+    //--------------------------------------------------------------------------------------------------
+    //
+    // Outputs are driven through the OLAT register rather than GPIO. Reading GPIO returns the levels sensed on the pins,
+    // which differ from the requested outputs when a pin is an input or is being pulled by external circuitry, so a
+    // read-modify-write of GPIO can flip pins the driver never meant to change. OLAT always holds the requested output
+    // levels, which also makes it the right source for the cached output latch.
+
+    /// Read an MCP23008 register. OLAT is only read from the device when the output latch is not cached yet.
+    fn read_register(&mut self, register: Register) -> Result<u8, Error<I2C_ERR>> {
+        if register == Register::OLAT {
+            if let Some(latch) = self.output_latch {
+                return Ok(latch);
+            }
+        }
+        self.flush_update()?;
+        let value = self.read_register_uncached(register)?;
+        if register == Register::OLAT {
+            self.output_latch = Some(value);
+        }
+        Ok(value)
+    }
+
+    /// Read a register from the device, bypassing the cached output latch
+    fn read_register_uncached(&mut self, register: Register) -> Result<u8, Error<I2C_ERR>> {
+        #[cfg(feature = "stats")]
+        self.record_transaction(2);
+        let mut data = [0u8];
+        let result = self
+            .i2c
+            .write_read(self.address, &[register.into()], &mut data);
+        self.track_bus_result(result)?;
+        Ok(data[0])
+    }
+
+    /// Write an MCP23008 register. While updating, OLAT writes are queued instead.
+    fn write_register(&mut self, register: Register, value: u8) -> Result<(), Error<I2C_ERR>> {
+        if self.updating && register == Register::OLAT {
+            return self.queue_output(value);
+        }
+        self.flush_update()?;
+        #[cfg(feature = "stats")]
+        self.record_transaction(2);
+        let result = self.i2c.write(self.address, &[register.into(), value]);
+        if register == Register::OLAT {
+            // the latch is unknown if the write failed
+            self.output_latch = result.is_ok().then_some(value);
+        }
+        self.track_bus_result(result)?;
+        #[cfg(feature = "verify")]
+        self.verify_register(register, value)?;
+        Ok(())
+    }
+
+    /// Queue an OLAT write to be sent by `flush_update()`, flushing first if the queue is full
+    fn queue_output(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
+        if self.update_len == UPDATE_BUFFER_LEN {
+            self.flush_update()?;
+        }
+        if self.update_len == 0 {
+            self.update_buffer[0] = Register::OLAT.into();
+            self.update_len = 1;
+        }
+        self.update_buffer[self.update_len] = value;
+        self.update_len += 1;
+        self.output_latch = Some(value);
+        Ok(())
+    }
+
+    /// Read a register back from the device and check it holds the value just written to it
+    #[cfg(feature = "verify")]
+    fn verify_register(&mut self, register: Register, expected: u8) -> Result<(), Error<I2C_ERR>> {
+        if self.read_register_uncached(register)? != expected {
+            if register == Register::OLAT {
+                self.output_latch = None;
+            }
+            return Err(Error::VerificationFailed);
+        }
+        Ok(())
+    }
+
+    /// Send the queued OLAT writes as one I2C transaction. `IOCON_SEQOP` makes every byte land in OLAT.
+    fn flush_update(&mut self) -> Result<(), Error<I2C_ERR>> {
+        let len = core::mem::take(&mut self.update_len);
+        if len > 1 {
+            #[cfg(feature = "stats")]
+            self.record_transaction(len);
+            let result = self.i2c.write(self.address, &self.update_buffer[..len]);
+            if result.is_err() {
+                // the latch is unknown if the write failed
+                self.output_latch = None;
+            }
+            self.track_bus_result(result)?;
+            #[cfg(feature = "verify")]
+            self.verify_register(Register::OLAT, self.update_buffer[len - 1])?;
+        }
+        Ok(())
+    }
+
+    /// Count an I2C transaction of `bytes` bytes in the statistics
+    #[cfg(feature = "stats")]
+    fn record_transaction(&mut self, bytes: usize) {
+        self.stats.transactions = self.stats.transactions.wrapping_add(1);
+        self.stats.bytes = self.stats.bytes.wrapping_add(bytes as u32);
+    }
+
+    /// Set or clear a single pin's bit in an MCP23008 register, preserving the other bits
+    fn set_register_bit(
+        &mut self,
+        register: Register,
+        pin: Mcp23008,
+        value: bool,
+    ) -> Result<(), Error<I2C_ERR>> {
+        let mut contents = self.read_register(register)?;
+        if value {
+            contents |= 1 << (pin as u8);
+        } else {
+            contents &= !(1 << (pin as u8));
+        }
+        self.write_register(register, contents)
+    }
+
+    /// Set the direction of a pin
+    fn set_pin_direction(
+        &mut self,
+        pin: Mcp23008,
+        direction: Direction,
+    ) -> Result<(), Error<I2C_ERR>> {
+        self.set_register_bit(Register::IODIR, pin, direction == Direction::Input)
+    }
+
+    /// Set the output level of a pin
+    fn set_pin_level(&mut self, pin: Mcp23008, level: Level) -> Result<(), Error<I2C_ERR>> {
+        self.set_register_bit(Register::OLAT, pin, level == Level::High)
+    }
+
+    //--------------------------------------------------------------------------------------------------
+    // Internal data writing functions
+    //--------------------------------------------------------------------------------------------------
+
+    /// Write 4 bits to the LCD
+    fn write_4_bits(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
+        let TimingProfile {
+            enable_pulse_us,
+            nibble_delay_us,
+            ..
+        } = self.timing;
+        if enable_pulse_us <= COMBINED_WRITE_PULSE_US {
+            self.queue_nibble(value)?;
+            if !self.updating {
+                self.flush_update()?;
+            }
+        } else {
+            let mut register_contents = self.nibble_outputs(value)?;
+            let enable_mask = 1 << (self.pins.enable as u8);
+
+            // write the new register contents
+            self.write_register(Register::OLAT, register_contents)?;
+
+            // pulse ENABLE pin using the known value of the register contents
+            self.wait_us(enable_pulse_us);
+            register_contents |= enable_mask; // set enable pin high
+            self.write_register(Register::OLAT, register_contents)?;
+            self.wait_us(enable_pulse_us);
+            register_contents &= !enable_mask; // set enable pin low
+            self.write_register(Register::OLAT, register_contents)?;
+        }
+        self.wait_us(nibble_delay_us);
+
+        Ok(())
+    }
+
+    /// Queue the data and enable pulse writes for 4 bits, to be sent as one multi-byte I2C write where the transfer time
+    /// of each byte provides the data setup time and enable pulse width
+    fn queue_nibble(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
+        let register_contents = self.nibble_outputs(value)?;
+        let enable_mask = 1 << (self.pins.enable as u8);
+        self.queue_output(register_contents)?;
+        self.queue_output(register_contents | enable_mask)?;
+        self.queue_output(register_contents)
+    }
+
+    /// Get the output latch value that presents 4 bits on the data pins with the enable pin low
+    fn nibble_outputs(&mut self, value: u8) -> Result<u8, Error<I2C_ERR>> {
         // get the current value of the register byte
-        let mut register_contents = self.register.read(Register::GPIO.into())?;
+        let mut register_contents = self.read_register(Register::OLAT)?;
 
         // set bit 0, data pin 4
-        for (index, pin) in DATA_PINS.iter().enumerate() {
+        for (index, pin) in self.pins.data.iter().enumerate() {
             let bit_mask = 1 << (*pin as u8);
             register_contents &= !bit_mask;
             if value & (1 << index) != 0 {
@@ -422,55 +2012,495 @@ where
         }
 
         // set the enable pin low in the register_contents
-        register_contents &= !(1 << (ENABLE_PIN as u8));
-
-        // write the new register contents
-        self.register
-            .write(Register::GPIO.into(), register_contents)?;
-
-        // pulse ENABLE pin quickly using the known value of the register contents
-        self.delay().delay_us(1);
-        register_contents |= 1 << (ENABLE_PIN as u8); // set enable pin high
-        self.register
-            .write(Register::GPIO.into(), register_contents)?;
-        self.delay().delay_us(1);
-        register_contents &= !(1 << (ENABLE_PIN as u8)); // set enable pin low
-        self.register
-            .write(Register::GPIO.into(), register_contents)?;
-        self.delay().delay_us(100);
+        register_contents &= !(1 << (self.pins.enable as u8));
+        Ok(register_contents)
+    }
 
+    /// Wait for a number of microseconds. While updating, the transfer time of the queued writes paces the LCD instead.
+    /// The `no-delay` feature skips the wait.
+    fn wait_us(&mut self, us: u16) {
+        if self.updating || cfg!(feature = "no-delay") {
+            return;
+        }
+        if let Some(feed) = self.watchdog_feed {
+            feed();
+            let mut remaining = us;
+            while remaining > 1000 {
+                self.delay.delay_us(1000);
+                feed();
+                remaining -= 1000;
+            }
+            self.delay.delay_us(remaining);
+        } else {
+            self.delay.delay_us(us);
+        }
+    }
+
+    /// Wait for a number of milliseconds, sending any queued writes first so the wait happens after them. The `no-delay`
+    /// feature skips the wait.
+    fn wait_ms(&mut self, ms: u16) -> Result<(), Error<I2C_ERR>> {
+        self.flush_update()?;
+        if cfg!(feature = "no-delay") {
+            return Ok(());
+        }
+        if let Some(feed) = self.watchdog_feed {
+            for _ in 0..ms {
+                feed();
+                self.delay.delay_ms(1);
+            }
+            feed();
+        } else {
+            self.delay.delay_ms(ms);
+        }
+        Ok(())
+    }
+
+    /// Set the RS pin for the next byte. When the byte goes out in one I2C write, a change of level is queued to go out
+    /// in that same write and an unchanged level is not sent at all.
+    fn set_register_select(&mut self, level: Level) -> Result<(), Error<I2C_ERR>> {
+        if self.timing.enable_pulse_us > COMBINED_WRITE_PULSE_US {
+            return self.set_pin_level(self.pins.rs, level);
+        }
+        let latch = self.read_register(Register::OLAT)?;
+        let rs_mask = 1 << (self.pins.rs as u8);
+        let contents = if level == Level::High {
+            latch | rs_mask
+        } else {
+            latch & !rs_mask
+        };
+        if contents != latch {
+            self.queue_output(contents)?;
+        }
         Ok(())
     }
 
     /// Write 8 bits to the LCD using 4 bit mode
     fn write_8_bits(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
+        if self.timing.enable_pulse_us <= COMBINED_WRITE_PULSE_US {
+            // both nibbles go out in one I2C write, as the LCD only needs the settle time once the whole byte is latched
+            self.queue_nibble(value >> 4)?;
+            self.queue_nibble(value & 0x0F)?;
+            if !self.updating {
+                self.flush_update()?;
+            }
+            self.wait_us(self.timing.nibble_delay_us);
+            return Ok(());
+        }
         self.write_4_bits(value >> 4)?;
         self.write_4_bits(value & 0x0F)?;
         Ok(())
     }
 
-    /// Send a command to the LCD
+    /// Send a command to the LCD. Returns `Error::NotInitialized` before `init()` or `attach()` succeeded.
     pub fn send_command(&mut self, command: u8) -> Result<(), Error<I2C_ERR>> {
-        self.register.set_gpio(RS_PIN, Level::Low)?;
+        if !self.initialized {
+            return Err(Error::NotInitialized);
+        }
+        self.reinit_if_pending()?;
+        self.wake()?;
+        self.set_register_select(Level::Low)?;
         self.write_8_bits(command)?;
+        #[cfg(feature = "stats")]
+        {
+            self.stats.commands = self.stats.commands.wrapping_add(1);
+        }
+        if command == LCD_CMD_CLEARDISPLAY {
+            self.shadow.cleared();
+        }
         Ok(())
     }
 
-    /// Send data to the LCD
+    /// Send data to the LCD. Data written to the display advances the tracked cursor position. Returns
+    /// `Error::NotInitialized` before `init()` or `attach()` succeeded.
     pub fn write_data(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
-        self.register.set_gpio(RS_PIN, Level::High)?;
+        if !self.initialized {
+            return Err(Error::NotInitialized);
+        }
+        self.reinit_if_pending()?;
+        self.wake()?;
+        if !self.cgram_addressed {
+            self.readdress_split_column()?;
+        }
+        self.set_register_select(Level::High)?;
         self.write_8_bits(value)?;
+        #[cfg(feature = "stats")]
+        {
+            self.stats.data_writes = self.stats.data_writes.wrapping_add(1);
+        }
+        if !self.cgram_addressed {
+            self.record_shadow(value);
+            if self.display_mode & LCD_FLAG_ENTRYSHIFTINCREMENT != 0 {
+                self.track_display_shift(self.display_mode & LCD_FLAG_ENTRYLEFT != 0);
+            }
+            self.advance_cursor();
+            if self.overflow_policy == OverflowPolicy::WrapToNextRow
+                && self.cursor_col >= self.lcd_type.cols()
+            {
+                self.wrap_to_next_row()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Drive the backlight pin without changing the requested backlight state
+    fn set_backlight_pin(&mut self, on: bool) -> Result<(), Error<I2C_ERR>> {
+        let level = if on { Level::High } else { Level::Low };
+        self.set_pin_level(self.pins.backlight, level)
+    }
+
+    /// Length of a DDRAM line, which is the period of the display shift
+    fn ddram_line_length(&self) -> u8 {
+        if self.display_function & LCD_FLAG_2LINE != 0 {
+            40
+        } else {
+            80
+        }
+    }
+
+    /// Record a display shift of one position to the left or right
+    fn track_display_shift(&mut self, left: bool) {
+        let line_length = self.ddram_line_length();
+        self.scroll_offset = if left {
+            (self.scroll_offset + 1) % line_length
+        } else {
+            (self.scroll_offset + line_length - 1) % line_length
+        };
+    }
+
+    /// Mark a custom character location as recently used for `load_glyph()`
+    fn touch_custom_char(&mut self, location: u8) {
+        self.custom_char_clock = self.custom_char_clock.wrapping_add(1);
+        self.custom_char_last_used[location as usize] = self.custom_char_clock;
+    }
+
+    /// Print a single character of text, expanding tab characters
+    fn print_text_char(&mut self, c: char) -> Result<(), Error<I2C_ERR>> {
+        if c == '\t' {
+            for _ in 0..self.spaces_to_next_tab_stop() {
+                self.print_char(' ')?;
+            }
+            Ok(())
+        } else {
+            self.print_char(c)
+        }
+    }
+
+    /// Erase part of the screen for the ANSI `ESC[nJ` sequence, restoring the cursor afterwards
+    fn erase_display(&mut self, mode: u8) -> Result<(), Error<I2C_ERR>> {
+        let row = self.cursor_row;
+        match mode {
+            0 => {
+                self.erase_line(0)?;
+                self.fill_rows(row + 1..self.lcd_type.rows(), ' ')?;
+            }
+            1 => {
+                self.fill_rows(0..row, ' ')?;
+                self.erase_line(1)?;
+            }
+            _ => {
+                self.clear()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Erase part of the cursor's row for the ANSI `ESC[nK` sequence, restoring the cursor afterwards
+    fn erase_line(&mut self, mode: u8) -> Result<(), Error<I2C_ERR>> {
+        let (col, row) = self.get_cursor();
+        let cols = self.lcd_type.cols();
+        let (start, end) = match mode {
+            0 => (col.min(cols), cols),
+            1 => (0, col.saturating_add(1).min(cols)),
+            _ => (0, cols),
+        };
+        if start < end {
+            self.set_cursor(start, row)?;
+            for _ in start..end {
+                self.write_data(b' ')?;
+            }
+        }
+        self.restore_cursor(col, row)?;
+        Ok(())
+    }
+
+    /// Print the digits of a converted number, applying the overflow policy
+    fn print_number(&mut self, number: &NumberBuffer) -> Result<(), Error<I2C_ERR>> {
+        for &byte in number.as_bytes() {
+            self.print_byte(byte)?;
+        }
+        Ok(())
+    }
+
+    /// Get the character code for a character that must take up exactly one column, using the replacement character if
+    /// the display has no glyph for it
+    fn single_char_code(&self, c: char) -> u8 {
+        self.map_char(c).unwrap_or(self.replacement_char)
+    }
+
+    /// Get the character code for a character, or `None` if the display has no glyph for it
+    fn map_char(&self, c: char) -> Option<u8> {
+        match (c, self.character_mapper) {
+            // custom characters printed with `CustomChar::as_char()` are never remapped
+            ('\0'..='\x07', _) => Some(c as u8),
+            (_, Some(mapper)) => mapper.map(c),
+            (_, None) => c.is_ascii().then_some(c as u8),
+        }
+    }
+
+    /// Print a single character, mapping it to a character code and applying the overflow policy
+    fn print_char(&mut self, c: char) -> Result<(), Error<I2C_ERR>> {
+        if let Some(code) = self.map_char(c) {
+            return self.print_byte(code);
+        }
+        if self.transliteration {
+            if let Some(text) = charset::transliterate(c) {
+                for byte in text.bytes() {
+                    self.print_byte(byte)?;
+                }
+                return Ok(());
+            }
+        }
+        self.print_byte(self.replacement_char)
+    }
+
+    /// Print a single character code, applying the overflow policy
+    fn print_byte(&mut self, byte: u8) -> Result<(), Error<I2C_ERR>> {
+        if self.apply_overflow_policy()? {
+            self.write_data(byte)?;
+        }
+        Ok(())
+    }
+
+    /// Get the number of spaces needed to move the cursor to the next tab stop in the current text flow direction
+    fn spaces_to_next_tab_stop(&self) -> u8 {
+        if self.display_mode & LCD_FLAG_ENTRYLEFT != 0 {
+            self.tab_width - self.cursor_col % self.tab_width
+        } else {
+            self.cursor_col % self.tab_width + 1
+        }
+    }
+
+    /// Apply the overflow policy before a character is printed. Returns `false` if the character should be dropped.
+    fn apply_overflow_policy(&mut self) -> Result<bool, Error<I2C_ERR>> {
+        // in right to left mode the column wraps around to 255 after column 0, so this covers both directions
+        if self.cgram_addressed || self.cursor_col < self.lcd_type.cols() {
+            return Ok(true);
+        }
+        match self.overflow_policy {
+            OverflowPolicy::Unchecked => Ok(true),
+            OverflowPolicy::Truncate => Ok(false),
+            OverflowPolicy::WrapToNextRow => {
+                self.wrap_to_next_row()?;
+                Ok(true)
+            }
+            OverflowPolicy::Error => Err(Error::ColumnOutOfRange {
+                col: self.cursor_col,
+                max: self.lcd_type.cols() - 1,
+            }),
+        }
+    }
+
+    /// Move the cursor to the start of the next row, wrapping from the last row back to the first row
+    fn wrap_to_next_row(&mut self) -> Result<(), Error<I2C_ERR>> {
+        let row = (self.cursor_row + 1) % self.lcd_type.rows();
+        self.set_cursor(self.row_start_col(), row)?;
+        Ok(())
+    }
+
+    /// Get the column text written across a whole row must start at for the current text flow direction
+    fn row_start_col(&self) -> u8 {
+        if self.display_mode & LCD_FLAG_ENTRYLEFT != 0 {
+            0
+        } else {
+            self.lcd_type.cols() - 1
+        }
+    }
+
+    /// Count consecutive I2C errors, scheduling a re-initialization once the automatic re-initialization limit is reached
+    fn track_bus_result<T>(&mut self, result: Result<T, I2C_ERR>) -> Result<T, Error<I2C_ERR>> {
+        match result {
+            Ok(value) => {
+                self.consecutive_errors = 0;
+                Ok(value)
+            }
+            Err(error) => {
+                self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+                if let Some(limit) = self.reinit_after_errors {
+                    self.reinit_pending |= self.consecutive_errors >= limit;
+                }
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Run a scheduled re-initialization, restoring the state the driver has cached
+    fn reinit_if_pending(&mut self) -> Result<(), Error<I2C_ERR>> {
+        if !self.reinit_pending {
+            return Ok(());
+        }
+        let (cursor_col, cursor_row) = self.get_cursor();
+        let scroll = self.scroll_offset();
+        let shadow = self.shadow;
+        self.init()?;
+        for (location, charmap) in self.custom_chars.into_iter().enumerate() {
+            if let Some(charmap) = charmap {
+                self.send_command(LCD_CMD_SETCGRAMADDR | ((location as u8) << 3))?;
+                self.cgram_addressed = true;
+                for charmap_byte in charmap {
+                    self.write_data(charmap_byte)?;
+                }
+            }
+        }
+        // write the contents left to right without shifting the display, then restore the entry mode
+        let display_mode = self.display_mode;
+        self.display_mode = LCD_FLAG_ENTRYLEFT | LCD_FLAG_ENTRYSHIFTDECREMENT;
+        let result = self.restore_contents(&shadow);
+        self.display_mode = display_mode;
+        result?;
+        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+        for _ in 0..scroll.unsigned_abs() {
+            if scroll > 0 {
+                self.scroll_display_left()?;
+            } else {
+                self.scroll_display_right()?;
+            }
+        }
+        self.restore_cursor(cursor_col, cursor_row)
+    }
+
+    /// Write the known cells of a saved shadow of the display contents back to the cleared display
+    fn restore_contents(&mut self, shadow: &Shadow) -> Result<(), Error<I2C_ERR>> {
+        self.send_command(LCD_CMD_ENTRYMODESET | self.display_mode)?;
+        for (col, row, code) in shadow.known_cells(self.lcd_type.cols(), self.lcd_type.rows()) {
+            self.write_cell(col, row, code)?;
+        }
+        Ok(())
+    }
+
+    /// Reset the tracked cursor state after a clear or home command
+    fn reset_cursor_state(&mut self) {
+        self.cursor_col = 0;
+        self.cursor_row = 0;
+        self.cgram_addressed = false;
+        self.scroll_offset = 0;
+    }
+
+    /// Send any queued writes and reset the tracked cursor state for a clear or home command the caller waits for
+    fn start_pending(&mut self, now_ms: u32) -> Result<PendingCommand, Error<I2C_ERR>> {
+        self.flush_update()?;
+        self.reset_cursor_state();
+        Ok(PendingCommand {
+            started_ms: now_ms,
+            duration_ms: self.timing.clear_home_delay_ms,
+        })
+    }
+
+    /// Get the column `offset` characters from `start` in the current text flow direction
+    fn flow_col(&self, start: u8, offset: u8) -> u8 {
+        if self.display_mode & LCD_FLAG_ENTRYLEFT != 0 {
+            start + offset
+        } else {
+            start - offset
+        }
+    }
+
+    /// Check that `height` rows starting at `row` are on the display
+    fn check_rows(&self, row: u8, height: u8) -> Result<(), Error<I2C_ERR>> {
+        let rows = self.lcd_type.rows();
+        if row as u16 + height as u16 > rows as u16 {
+            return Err(Error::RowOutOfRange {
+                row: row.saturating_add(height.saturating_sub(1)),
+                max: rows - 1,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that `width` columns starting at `col` are on the display
+    fn check_cols(&self, col: u8, width: u8) -> Result<(), Error<I2C_ERR>> {
+        let cols = self.lcd_type.cols();
+        if col as u16 + width as u16 > cols as u16 {
+            return Err(Error::ColumnOutOfRange {
+                col: col.saturating_add(width.saturating_sub(1)),
+                max: cols - 1,
+            });
+        }
+        Ok(())
+    }
+
+    /// Write a character code to a cell unless the shadow shows the cell already holds it
+    fn write_cell(&mut self, col: u8, row: u8, code: u8) -> Result<(), Error<I2C_ERR>> {
+        if self.shadow.holds(col, row, code) {
+            return Ok(());
+        }
+        if self.cgram_addressed || self.get_cursor() != (col, row) {
+            self.set_cursor(col, row)?;
+        }
+        self.write_data(code)
+    }
+
+    /// Record a character code written at the cursor in the shadow of the display contents. Text that runs past the
+    /// end of a row may land on another row, so the whole shadow is forgotten when that happens.
+    fn record_shadow(&mut self, code: u8) {
+        if self.cursor_col >= self.lcd_type.cols() {
+            self.shadow.forget();
+        } else {
+            self.shadow.record(self.cursor_col, self.cursor_row, code);
+        }
+    }
+
+    /// Move the cursor to a previously tracked position, which may be past the end of the row
+    fn restore_cursor(&mut self, col: u8, row: u8) -> Result<(), Error<I2C_ERR>> {
+        self.send_command(LCD_CMD_SETDDRAMADDR | self.lcd_type.ddram_address(col, row))?;
+        self.cursor_col = col;
+        self.cursor_row = row;
+        self.cgram_addressed = false;
+        Ok(())
+    }
+
+    /// Move the tracked cursor position one column in the current text flow direction
+    fn advance_cursor(&mut self) {
+        if self.display_mode & LCD_FLAG_ENTRYLEFT != 0 {
+            self.cursor_col = self.cursor_col.wrapping_add(1);
+        } else {
+            self.cursor_col = self.cursor_col.wrapping_sub(1);
+        }
+    }
+
+    /// Re-address the cursor when the next character crosses the display's split column, so text continues on
+    /// the visible half of the row rather than in offscreen DDRAM.
+    fn readdress_split_column(&mut self) -> Result<(), Error<I2C_ERR>> {
+        if let Some(split) = self.lcd_type.split_column() {
+            let crossing = if self.display_mode & LCD_FLAG_ENTRYLEFT != 0 {
+                self.cursor_col == split
+            } else {
+                self.cursor_col == split - 1
+            };
+            if crossing {
+                self.send_command(
+                    LCD_CMD_SETDDRAMADDR
+                        | self
+                            .lcd_type
+                            .ddram_address(self.cursor_col, self.cursor_row),
+                )?;
+            }
+        }
         Ok(())
     }
 
     /// Pulse the enable pin
     fn pulse_enable(&mut self) -> Result<(), Error<I2C_ERR>> {
-        self.register.set_gpio(ENABLE_PIN, Level::Low)?;
-        self.delay().delay_us(1);
-        self.register.set_gpio(ENABLE_PIN, Level::High)?;
-        self.delay().delay_us(1);
-        self.register.set_gpio(ENABLE_PIN, Level::Low)?;
-        self.delay().delay_us(100);
+        let TimingProfile {
+            enable_pulse_us,
+            nibble_delay_us,
+            ..
+        } = self.timing;
+        self.set_pin_level(self.pins.enable, Level::Low)?;
+        self.wait_us(enable_pulse_us);
+        self.set_pin_level(self.pins.enable, Level::High)?;
+        self.wait_us(enable_pulse_us);
+        self.set_pin_level(self.pins.enable, Level::Low)?;
+        self.wait_us(nibble_delay_us);
 
         Ok(())
     }
@@ -483,7 +2513,28 @@ where
     D: DelayMs<u16> + DelayUs<u16>,
 {
     fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
-        if let Err(_error) = self.print(s) {
+        if let Err(error) = self.print(s) {
+            self.last_error = Some(error.without_i2c_error());
+            return Err(core::fmt::Error);
+        }
+        Ok(())
+    }
+}
+
+/// Forwards formatted text to the LCD for `print_fmt()`, keeping the error behind a failed write including its I2C error
+struct FmtWriter<'a, I2C, D, I2C_ERR> {
+    lcd: &'a mut LcdBackpack<I2C, D>,
+    error: Option<Error<I2C_ERR>>,
+}
+
+impl<I2C, I2C_ERR, D> core::fmt::Write for FmtWriter<'_, I2C, D, I2C_ERR>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
+        if let Err(error) = self.lcd.print(s) {
+            self.error = Some(error);
             return Err(core::fmt::Error);
         }
         Ok(())
@@ -0,0 +1,309 @@
+use crate::{number::NumberBuffer, widgets::write_field, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Maximum nesting depth of submenus, including the top level menu
+const MAX_DEPTH: usize = 4;
+
+/// An entry in a menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuItem<'a> {
+    /// An item that reports [`MenuEvent::Action`] with its id when selected
+    Action {
+        /// Text shown for the item
+        label: &'a str,
+        /// Id reported when the item is selected
+        id: u8,
+    },
+    /// An item that opens a list of child items when selected
+    Submenu {
+        /// Text shown for the item
+        label: &'a str,
+        /// Items of the submenu
+        items: &'a [MenuItem<'a>],
+    },
+    /// An item showing one of the menu's values, which is edited with Up and Down after selecting the item
+    Value {
+        /// Text shown for the item
+        label: &'a str,
+        /// Index of the value in the menu's values
+        id: u8,
+        /// Smallest value
+        min: i32,
+        /// Largest value
+        max: i32,
+        /// Amount each Up or Down input changes the value by
+        step: i32,
+    },
+}
+
+impl<'a> MenuItem<'a> {
+    /// Get the text shown for the item
+    pub fn label(&self) -> &'a str {
+        match self {
+            MenuItem::Action { label, .. }
+            | MenuItem::Submenu { label, .. }
+            | MenuItem::Value { label, .. } => label,
+        }
+    }
+}
+
+/// A navigation input fed to [`Menu::handle()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuInput {
+    /// Move the selection up, or increase the value being edited
+    Up,
+    /// Move the selection down, or decrease the value being edited
+    Down,
+    /// Activate the selected item, or finish editing a value
+    Select,
+    /// Return to the parent menu, or finish editing a value
+    Back,
+}
+
+/// The outcome of a [`MenuInput`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuEvent {
+    /// Nothing for the application to act on
+    None,
+    /// An action item was selected
+    Action(u8),
+    /// A value was changed
+    ValueChanged {
+        /// Index of the value
+        id: u8,
+        /// The new value
+        value: i32,
+    },
+    /// Back was pressed in the top level menu
+    Exit,
+}
+
+/// The items, selection and scroll position of an open menu level
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Level<'a> {
+    items: &'a [MenuItem<'a>],
+    selected: usize,
+    top: usize,
+}
+
+/// A menu of actions, submenus and value entries, navigated with Up, Down, Select and Back inputs. The menu shows as many
+/// items as fit in its window, with `>` in front of the selected item, and scrolls to keep the selection in view. Value
+/// items show their value right aligned, with `*` in front while the value is being edited. Submenus are marked with
+/// a `>` at the end of their row, and can be nested up to 4 levels deep. The menu holds `VALUES` values for its value
+/// items, addressed by the items' ids.
+///
+/// ```rust,ignore
+/// const SETTINGS: [MenuItem; 2] = [
+///     MenuItem::Value { label: "Contrast", id: 0, min: 0, max: 10, step: 1 },
+///     MenuItem::Action { label: "Save", id: 1 },
+/// ];
+/// const ROOT: [MenuItem; 2] = [
+///     MenuItem::Submenu { label: "Settings", items: &SETTINGS },
+///     MenuItem::Action { label: "Reboot", id: 2 },
+/// ];
+/// let mut menu: Menu<1> = Menu::new(&ROOT, lcd.rows());
+/// menu.draw(&mut lcd)?;
+/// if let MenuEvent::Action(id) = menu.handle(MenuInput::Select) { ... }
+/// menu.draw(&mut lcd)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Menu<'a, const VALUES: usize = 0> {
+    levels: [Level<'a>; MAX_DEPTH],
+    depth: usize,
+    rows: u8,
+    editing: bool,
+    values: [i32; VALUES],
+}
+
+impl<'a, const VALUES: usize> Menu<'a, VALUES> {
+    /// Create a menu of the top level `items`, shown in a window of `rows` rows at the top of the display. All values
+    /// start at 0.
+    pub fn new(items: &'a [MenuItem<'a>], rows: u8) -> Self {
+        let level = Level {
+            items,
+            selected: 0,
+            top: 0,
+        };
+        Self {
+            levels: [level; MAX_DEPTH],
+            depth: 0,
+            rows: rows.max(1),
+            editing: false,
+            values: [0; VALUES],
+        }
+    }
+
+    /// Get a value, or `None` if the id is out of range
+    pub fn value(&self, id: u8) -> Option<i32> {
+        self.values.get(id as usize).copied()
+    }
+
+    /// Set a value. Ids out of range are ignored.
+    pub fn set_value(&mut self, id: u8, value: i32) -> &mut Self {
+        if let Some(slot) = self.values.get_mut(id as usize) {
+            *slot = value;
+        }
+        self
+    }
+
+    /// Get the selected item, or `None` if the open menu has no items
+    pub fn selected(&self) -> Option<&'a MenuItem<'a>> {
+        let level = self.level();
+        level.items.get(level.selected)
+    }
+
+    /// Returns `true` while a value is being edited
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Close all submenus and select the first item of the top level menu
+    pub fn reset(&mut self) -> &mut Self {
+        self.depth = 0;
+        self.editing = false;
+        self.levels[0].selected = 0;
+        self.levels[0].top = 0;
+        self
+    }
+
+    /// Apply a navigation input, returning what the application should act on. Call `draw()` afterwards to show the
+    /// result.
+    pub fn handle(&mut self, input: MenuInput) -> MenuEvent {
+        if self.editing {
+            return self.edit(input);
+        }
+        let count = self.level().items.len();
+        match input {
+            MenuInput::Up => {
+                let level = self.level_mut();
+                level.selected = level.selected.saturating_sub(1);
+            }
+            MenuInput::Down => {
+                let level = self.level_mut();
+                if level.selected + 1 < count {
+                    level.selected += 1;
+                }
+            }
+            MenuInput::Select => match self.selected() {
+                Some(&MenuItem::Action { id, .. }) => return MenuEvent::Action(id),
+                Some(&MenuItem::Submenu { items, .. }) if self.depth + 1 < MAX_DEPTH => {
+                    self.depth += 1;
+                    self.levels[self.depth] = Level {
+                        items,
+                        selected: 0,
+                        top: 0,
+                    };
+                }
+                Some(MenuItem::Value { .. }) => self.editing = true,
+                _ => {}
+            },
+            MenuInput::Back => {
+                if self.depth == 0 {
+                    return MenuEvent::Exit;
+                }
+                self.depth -= 1;
+            }
+        }
+        self.scroll_to_selection();
+        MenuEvent::None
+    }
+
+    /// Draw the visible items of the open menu across the full width of the display
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(0, self.rows)?;
+        let cols = lcd.cols();
+        let level = self.level();
+        for row in 0..self.rows {
+            let index = level.top + row as usize;
+            let Some(item) = level.items.get(index) else {
+                write_field(lcd, 0, row, cols, "")?;
+                continue;
+            };
+            let indicator = match (index == level.selected, self.editing) {
+                (true, true) => b'*',
+                (true, false) => b'>',
+                _ => b' ',
+            };
+            lcd.write_cell(0, row, indicator)?;
+            match *item {
+                MenuItem::Value { label, id, .. } => {
+                    let number = NumberBuffer::signed(self.value(id).unwrap_or(0));
+                    let digits = number.as_bytes();
+                    let digits = &digits[digits
+                        .len()
+                        .saturating_sub((cols as usize).saturating_sub(1))..];
+                    let value_col = cols - digits.len() as u8;
+                    write_field(lcd, 1, row, value_col.saturating_sub(1), label)?;
+                    for (offset, &digit) in digits.iter().enumerate() {
+                        lcd.write_cell(value_col + offset as u8, row, digit)?;
+                    }
+                }
+                MenuItem::Submenu { label, .. } => {
+                    write_field(lcd, 1, row, cols.saturating_sub(2), label)?;
+                    // a single column only has room for the selection indicator
+                    if cols > 1 {
+                        lcd.write_cell(cols - 1, row, b'>')?;
+                    }
+                }
+                MenuItem::Action { label, .. } => {
+                    write_field(lcd, 1, row, cols.saturating_sub(1), label)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply an input to the value being edited
+    fn edit(&mut self, input: MenuInput) -> MenuEvent {
+        let Some(&MenuItem::Value {
+            id, min, max, step, ..
+        }) = self.selected()
+        else {
+            self.editing = false;
+            return MenuEvent::None;
+        };
+        let Some(current) = self.value(id) else {
+            self.editing = false;
+            return MenuEvent::None;
+        };
+        let value = match input {
+            MenuInput::Up => current.saturating_add(step).min(max),
+            MenuInput::Down => current.saturating_sub(step).max(min),
+            MenuInput::Select | MenuInput::Back => {
+                self.editing = false;
+                return MenuEvent::None;
+            }
+        };
+        if value == current {
+            return MenuEvent::None;
+        }
+        self.set_value(id, value);
+        MenuEvent::ValueChanged { id, value }
+    }
+
+    /// Scroll the open menu so the selected item is in the window
+    fn scroll_to_selection(&mut self) {
+        let rows = self.rows;
+        let level = self.level_mut();
+        if level.selected < level.top {
+            level.top = level.selected;
+        } else if level.selected >= level.top + rows as usize {
+            level.top = level.selected + 1 - rows as usize;
+        }
+    }
+
+    fn level(&self) -> &Level<'a> {
+        &self.levels[self.depth]
+    }
+
+    fn level_mut(&mut self) -> &mut Level<'a> {
+        &mut self.levels[self.depth]
+    }
+}
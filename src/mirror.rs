@@ -0,0 +1,218 @@
+use crate::{CustomChar, Error, InitError, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Mirrors every command and data write onto two LCD backpacks, keeping both displays in sync. This is useful when a
+/// front panel display and a debug display should always show the same content.
+///
+/// Each operation is applied to the primary backpack first and then to the secondary backpack. If the primary
+/// backpack fails, the operation is not applied to the secondary backpack.
+pub struct MirroredLcd<I2C_A, D_A, I2C_B, D_B> {
+    primary: LcdBackpack<I2C_A, D_A>,
+    secondary: LcdBackpack<I2C_B, D_B>,
+    last_error: Option<Error<()>>,
+}
+
+impl<I2C_A, D_A, I2C_B, D_B, I2C_ERR> MirroredLcd<I2C_A, D_A, I2C_B, D_B>
+where
+    I2C_A: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D_A: DelayMs<u16> + DelayUs<u16>,
+    I2C_B: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D_B: DelayMs<u16> + DelayUs<u16>,
+{
+    /// Create a new mirrored LCD from two LCD backpacks
+    pub fn new(primary: LcdBackpack<I2C_A, D_A>, secondary: LcdBackpack<I2C_B, D_B>) -> Self {
+        Self {
+            primary,
+            secondary,
+            last_error: None,
+        }
+    }
+
+    /// Get a mutable reference to the primary LCD backpack
+    pub fn primary(&mut self) -> &mut LcdBackpack<I2C_A, D_A> {
+        &mut self.primary
+    }
+
+    /// Get a mutable reference to the secondary LCD backpack
+    pub fn secondary(&mut self) -> &mut LcdBackpack<I2C_B, D_B> {
+        &mut self.secondary
+    }
+
+    /// Consume the mirrored LCD and return the two LCD backpacks
+    pub fn release(self) -> (LcdBackpack<I2C_A, D_A>, LcdBackpack<I2C_B, D_B>) {
+        (self.primary, self.secondary)
+    }
+
+    /// Take the error behind the most recent failed write through `core::fmt::Write`, such as with the `write!`
+    /// macro. A bus failure is returned as `Error::I2cError(())`. Returns `None` if no write failed since the error was
+    /// last taken.
+    pub fn take_last_error(&mut self) -> Option<Error<()>> {
+        self.last_error.take()
+    }
+
+    /// Initialize both LCDs
+    pub fn init(&mut self) -> Result<&mut Self, InitError<I2C_ERR>> {
+        self.primary.init()?;
+        self.secondary.init()?;
+        Ok(self)
+    }
+
+    /// Clear both displays
+    pub fn clear(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.clear()?;
+        self.secondary.clear()?;
+        Ok(self)
+    }
+
+    /// Set the cursor to the home position on both displays
+    pub fn home(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.home()?;
+        self.secondary.home()?;
+        Ok(self)
+    }
+
+    /// Set the cursor position at specified column and row on both displays
+    pub fn set_cursor(&mut self, col: u8, row: u8) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.set_cursor(col, row)?;
+        self.secondary.set_cursor(col, row)?;
+        Ok(self)
+    }
+
+    /// Set the cursor visibility on both displays
+    pub fn show_cursor(&mut self, show_cursor: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.show_cursor(show_cursor)?;
+        self.secondary.show_cursor(show_cursor)?;
+        Ok(self)
+    }
+
+    /// Set the cursor blinking on both displays
+    pub fn blink_cursor(&mut self, blink_cursor: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.blink_cursor(blink_cursor)?;
+        self.secondary.blink_cursor(blink_cursor)?;
+        Ok(self)
+    }
+
+    /// Set the display visibility on both displays
+    pub fn show_display(&mut self, show_display: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.show_display(show_display)?;
+        self.secondary.show_display(show_display)?;
+        Ok(self)
+    }
+
+    /// Turn the backlight on or off on both displays
+    pub fn backlight(&mut self, on: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.backlight(on)?;
+        self.secondary.backlight(on)?;
+        Ok(self)
+    }
+
+    /// Scroll both displays to the left
+    pub fn scroll_display_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.scroll_display_left()?;
+        self.secondary.scroll_display_left()?;
+        Ok(self)
+    }
+
+    /// Scroll both displays to the right
+    pub fn scroll_display_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.scroll_display_right()?;
+        self.secondary.scroll_display_right()?;
+        Ok(self)
+    }
+
+    /// Set the text flow direction to left to right on both displays
+    pub fn left_to_right(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.left_to_right()?;
+        self.secondary.left_to_right()?;
+        Ok(self)
+    }
+
+    /// Set the text flow direction to right to left on both displays
+    pub fn right_to_left(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.right_to_left()?;
+        self.secondary.right_to_left()?;
+        Ok(self)
+    }
+
+    /// Set the auto scroll mode on both displays
+    pub fn autoscroll(&mut self, autoscroll: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.autoscroll(autoscroll)?;
+        self.secondary.autoscroll(autoscroll)?;
+        Ok(self)
+    }
+
+    /// Set the controller's line mode on both displays
+    pub fn two_line_mode(&mut self, two_line: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.two_line_mode(two_line)?;
+        self.secondary.two_line_mode(two_line)?;
+        Ok(self)
+    }
+
+    /// Set the 5x10 dot font on both displays
+    pub fn font_5x10(&mut self, font_5x10: bool) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.font_5x10(font_5x10)?;
+        self.secondary.font_5x10(font_5x10)?;
+        Ok(self)
+    }
+
+    /// Create a new custom character on both displays
+    pub fn create_char(
+        &mut self,
+        location: u8,
+        charmap: [u8; 8],
+    ) -> Result<CustomChar, Error<I2C_ERR>> {
+        self.primary.create_char(location, charmap)?;
+        self.secondary.create_char(location, charmap)
+    }
+
+    /// Write a custom character created with `create_char()` on both displays
+    pub fn write_custom_char(
+        &mut self,
+        custom_char: CustomChar,
+    ) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.write_custom_char(custom_char)?;
+        self.secondary.write_custom_char(custom_char)?;
+        Ok(self)
+    }
+
+    /// Prints a string to both displays at the current cursor position
+    pub fn print(&mut self, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.primary.print(text)?;
+        self.secondary.print(text)?;
+        Ok(self)
+    }
+
+    /// Send a command to both LCDs
+    pub fn send_command(&mut self, command: u8) -> Result<(), Error<I2C_ERR>> {
+        self.primary.send_command(command)?;
+        self.secondary.send_command(command)?;
+        Ok(())
+    }
+
+    /// Send data to both LCDs
+    pub fn write_data(&mut self, value: u8) -> Result<(), Error<I2C_ERR>> {
+        self.primary.write_data(value)?;
+        self.secondary.write_data(value)?;
+        Ok(())
+    }
+}
+
+/// Implement the `core::fmt::Write` trait for the mirrored LCD, allowing it to be used with the `write!` macro.
+impl<I2C_A, D_A, I2C_B, D_B, I2C_ERR> core::fmt::Write for MirroredLcd<I2C_A, D_A, I2C_B, D_B>
+where
+    I2C_A: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D_A: DelayMs<u16> + DelayUs<u16>,
+    I2C_B: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D_B: DelayMs<u16> + DelayUs<u16>,
+{
+    fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
+        if let Err(error) = self.print(s) {
+            self.last_error = Some(error.without_i2c_error());
+            return Err(core::fmt::Error);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use crate::Padding;
+
+/// Large enough for a `u32` in binary, the longest number printed
+const NUMBER_BUFFER_LEN: usize = 33;
+/// An `i32` has at most 10 digits, so more decimal places would only add leading zeros
+pub(crate) const MAX_DECIMALS: u8 = 9;
+
+/// The ASCII digits of a number, converted without `core::fmt` to keep the code size small. The digits are stored at
+/// the end of a fixed buffer.
+pub(crate) struct NumberBuffer {
+    buf: [u8; NUMBER_BUFFER_LEN],
+    start: usize,
+}
+
+impl NumberBuffer {
+    /// Convert an unsigned number to digits in a radix in the range 2-16. Hexadecimal digits are upper case.
+    pub(crate) fn unsigned(value: u32, radix: u32) -> Self {
+        let mut number = Self::new();
+        number.push_digits(value, radix);
+        number
+    }
+
+    /// Convert a signed number to decimal digits, with a leading `-` for negative numbers
+    pub(crate) fn signed(value: i32) -> Self {
+        let mut number = Self::unsigned(value.unsigned_abs(), 10);
+        if value < 0 {
+            number.push(b'-');
+        }
+        number
+    }
+
+    /// Convert a fixed point number to decimal digits with `decimals` digits after the decimal point, so a value of
+    /// 2347 with 2 decimals becomes `23.47`. The number of decimals is limited to `MAX_DECIMALS`.
+    pub(crate) fn fixed(value: i32, decimals: u8) -> Self {
+        let mut number = Self::new();
+        let mut magnitude = value.unsigned_abs();
+        let decimals = decimals.min(MAX_DECIMALS);
+        for _ in 0..decimals {
+            number.push(b'0' + (magnitude % 10) as u8);
+            magnitude /= 10;
+        }
+        if decimals > 0 {
+            number.push(b'.');
+        }
+        number.push_digits(magnitude, 10);
+        if value < 0 {
+            number.push(b'-');
+        }
+        number
+    }
+
+    /// Right align the number in a field of `width` characters. Zero padding is inserted after a leading `-`. Numbers
+    /// wider than the field are left as is.
+    pub(crate) fn pad(&mut self, width: u8, padding: Padding) {
+        let width = (width as usize).min(NUMBER_BUFFER_LEN);
+        let negative = self.as_bytes().first() == Some(&b'-');
+        let fill = match padding {
+            Padding::Space => b' ',
+            Padding::Zero => {
+                if negative {
+                    self.start += 1;
+                }
+                b'0'
+            }
+        };
+        let sign_width = usize::from(negative && padding == Padding::Zero);
+        while self.as_bytes().len() + sign_width < width {
+            self.push(fill);
+        }
+        if sign_width == 1 {
+            self.push(b'-');
+        }
+    }
+
+    /// The converted number as ASCII bytes
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.buf[self.start..]
+    }
+
+    fn new() -> Self {
+        Self {
+            buf: [0; NUMBER_BUFFER_LEN],
+            start: NUMBER_BUFFER_LEN,
+        }
+    }
+
+    fn push_digits(&mut self, mut value: u32, radix: u32) {
+        loop {
+            let digit = (value % radix) as u8;
+            self.push(if digit < 10 {
+                b'0' + digit
+            } else {
+                b'A' + digit - 10
+            });
+            value /= radix;
+            if value == 0 {
+                break;
+            }
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.start -= 1;
+        self.buf[self.start] = byte;
+    }
+}
+
+/// Scale a floating point number by 10^`decimals` and round it half away from zero, clamping it to the `i32` range
+#[cfg(feature = "float")]
+pub(crate) fn f32_to_fixed(value: f32, decimals: u8) -> i32 {
+    let mut scaled = value;
+    for _ in 0..decimals {
+        scaled *= 10.0;
+    }
+    // float to integer casts saturate
+    if scaled < 0.0 {
+        (scaled - 0.5) as i32
+    } else {
+        (scaled + 0.5) as i32
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Error, FrameBuffer, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A set of `N` screens, each a [`FrameBuffer`], of which one is shown at a time. Every page can be rendered at any
+/// time, but only the active page is flushed to the LCD, so a dashboard can keep all of its views up to date and
+/// cycle between them cleanly. Switching pages redraws the whole display on the next flush.
+///
+/// ```rust,ignore
+/// let mut pages: Pages<16, 2, 3> = Pages::new();
+/// write!(pages.page(0).unwrap().set_cursor(0, 0), "Temp {}C", temp)?;
+/// write!(pages.page(1).unwrap().set_cursor(0, 0), "RH {}%", humidity)?;
+/// pages.next().flush(&mut lcd)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pages<const COLS: usize, const ROWS: usize, const N: usize> {
+    pages: [FrameBuffer<COLS, ROWS>; N],
+    active: usize,
+}
+
+impl<const COLS: usize, const ROWS: usize, const N: usize> Pages<COLS, ROWS, N> {
+    // evaluated when `new` is instantiated, so a set without pages fails to compile
+    const HAS_PAGES: () = assert!(N > 0, "Pages needs at least one page");
+
+    /// Create a set of blank pages with the first page active. `N` must be at least 1, which is checked at compile time.
+    pub const fn new() -> Self {
+        let () = Self::HAS_PAGES;
+        Self {
+            pages: [FrameBuffer::new(); N],
+            active: 0,
+        }
+    }
+
+    /// Get a page to render into, or `None` if there is no page with that id
+    pub fn page(&mut self, id: usize) -> Option<&mut FrameBuffer<COLS, ROWS>> {
+        self.pages.get_mut(id)
+    }
+
+    /// Get the active page
+    pub fn active_page(&mut self) -> &mut FrameBuffer<COLS, ROWS> {
+        &mut self.pages[self.active]
+    }
+
+    /// Get the id of the active page
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Activate the next page, wrapping from the last page to the first
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> &mut Self {
+        self.activate((self.active + 1) % N);
+        self
+    }
+
+    /// Activate the previous page, wrapping from the first page to the last
+    pub fn prev(&mut self) -> &mut Self {
+        self.activate((self.active + N - 1) % N);
+        self
+    }
+
+    /// Activate a page. Ids without a page are ignored.
+    pub fn goto(&mut self, id: usize) -> &mut Self {
+        if id < N {
+            self.activate(id);
+        }
+        self
+    }
+
+    /// Push the changes of the active page to the LCD
+    pub fn flush<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        self.active_page().flush(lcd)
+    }
+
+    /// Make a page the active page, marking it for a full redraw if it was not active
+    fn activate(&mut self, id: usize) {
+        if id != self.active {
+            self.active = id;
+            self.pages[id].invalidate();
+        }
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize, const N: usize> Default for Pages<COLS, ROWS, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
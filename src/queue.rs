@@ -0,0 +1,95 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A display operation that can be queued and executed later by `LcdBackpack::pump()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LcdCommand {
+    /// Clear the display
+    Clear,
+    /// Move the cursor to the home position
+    Home,
+    /// Move the cursor to the column and row
+    SetCursor { col: u8, row: u8 },
+    /// Clear a single row
+    ClearRow(u8),
+    /// Print a character
+    Char(char),
+    /// Write a raw character code, such as a custom character
+    Byte(u8),
+    /// Turn the backlight on or off
+    Backlight(bool),
+    /// Set the display visibility
+    ShowDisplay(bool),
+    /// Set the cursor visibility
+    ShowCursor(bool),
+    /// Set the cursor blinking
+    BlinkCursor(bool),
+}
+
+/// A fixed size queue of display operations. Holds up to `N - 1` commands. Split it into a [`CommandProducer`] and a
+/// [`CommandConsumer`] so commands can be queued from anywhere, including interrupt handlers, without touching the I2C
+/// bus, and executed from the main loop.
+///
+/// ```rust,ignore
+/// static mut QUEUE: CommandQueue<32> = CommandQueue::new();
+///
+/// let (mut producer, mut consumer) = unsafe { QUEUE.split() };
+/// // in an interrupt handler or other time critical code
+/// enqueue_text(&mut producer, "Alarm!");
+/// // in the main loop
+/// lcd.pump(&mut consumer)?;
+/// ```
+pub type CommandQueue<const N: usize> = heapless::spsc::Queue<LcdCommand, N>;
+
+/// The producing end of a [`CommandQueue`]
+pub type CommandProducer<'a, const N: usize> = heapless::spsc::Producer<'a, LcdCommand, N>;
+
+/// The consuming end of a [`CommandQueue`], drained by `LcdBackpack::pump()`
+pub type CommandConsumer<'a, const N: usize> = heapless::spsc::Consumer<'a, LcdCommand, N>;
+
+/// Queue the characters of a string. Returns `false` if the queue filled up, in which case the remaining characters
+/// were not queued.
+pub fn enqueue_text<const N: usize>(producer: &mut CommandProducer<'_, N>, text: &str) -> bool {
+    text.chars()
+        .all(|c| producer.enqueue(LcdCommand::Char(c)).is_ok())
+}
+
+impl<I2C, I2C_ERR, D> LcdBackpack<I2C, D>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    /// Execute every command queued in a [`CommandQueue`], returning the number of commands executed. Call this from
+    /// the main loop. If a command fails, the error is returned, the failed command is dropped and the remaining commands
+    /// stay queued.
+    pub fn pump<const N: usize>(
+        &mut self,
+        consumer: &mut CommandConsumer<'_, N>,
+    ) -> Result<usize, Error<I2C_ERR>> {
+        let mut executed = 0;
+        while let Some(command) = consumer.dequeue() {
+            self.execute_command(command)?;
+            executed += 1;
+        }
+        Ok(executed)
+    }
+
+    /// Execute a single queued command
+    fn execute_command(&mut self, command: LcdCommand) -> Result<(), Error<I2C_ERR>> {
+        match command {
+            LcdCommand::Clear => self.clear().map(|_| ()),
+            LcdCommand::Home => self.home().map(|_| ()),
+            LcdCommand::SetCursor { col, row } => self.set_cursor(col, row).map(|_| ()),
+            LcdCommand::ClearRow(row) => self.clear_row(row).map(|_| ()),
+            LcdCommand::Char(c) => self.print_text_char(c),
+            LcdCommand::Byte(code) => self.print_byte(code),
+            LcdCommand::Backlight(on) => self.backlight(on).map(|_| ()),
+            LcdCommand::ShowDisplay(show) => self.show_display(show).map(|_| ()),
+            LcdCommand::ShowCursor(show) => self.show_cursor(show).map(|_| ()),
+            LcdCommand::BlinkCursor(blink) => self.blink_cursor(blink).map(|_| ()),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A rectangular part of the display with its own cursor. Positions are relative to the region's top left corner and
+/// text is clipped to the region, so independent parts of an application, such as a status bar and a content area,
+/// can each draw into their own region without knowing where the others are.
+///
+/// ```rust,ignore
+/// let mut status = Region::new(0, 0, 20, 1);
+/// let mut content = Region::new(0, 1, 20, 3);
+/// status.print(&mut lcd, "Ready")?;
+/// content.clear(&mut lcd)?.print(&mut lcd, "line one\nline two")?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    col: u8,
+    row: u8,
+    width: u8,
+    height: u8,
+    cursor_col: u8,
+    cursor_row: u8,
+}
+
+impl Region {
+    /// Create a region `width` columns wide and `height` rows high with its top left corner at the specified column
+    /// and row of the display
+    pub const fn new(col: u8, row: u8, width: u8, height: u8) -> Self {
+        Self {
+            col,
+            row,
+            width,
+            height,
+            cursor_col: 0,
+            cursor_row: 0,
+        }
+    }
+
+    /// Get the width of the region in columns
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    /// Get the height of the region in rows
+    pub fn height(&self) -> u8 {
+        self.height
+    }
+
+    /// Move the region's cursor to the specified column and row within the region. Characters written while the cursor
+    /// is outside the region are dropped.
+    pub fn set_cursor(&mut self, col: u8, row: u8) -> &mut Self {
+        self.cursor_col = col;
+        self.cursor_row = row;
+        self
+    }
+
+    /// Get the region's cursor position as a (column, row) tuple within the region
+    pub fn get_cursor(&self) -> (u8, u8) {
+        (self.cursor_col, self.cursor_row)
+    }
+
+    /// Print a string at the region's cursor position. A `\n` moves the cursor to the start of the next row of the
+    /// region. Characters past the right or bottom edge of the region are dropped.
+    pub fn print<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        text: &str,
+    ) -> Result<&mut Self, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        for c in text.chars() {
+            if c == '\n' {
+                self.cursor_col = 0;
+                self.cursor_row = self.cursor_row.saturating_add(1);
+                continue;
+            }
+            if self.cursor_col < self.width && self.cursor_row < self.height {
+                let code = lcd.single_char_code(c);
+                self.write_cell(lcd, self.cursor_col, self.cursor_row, code)?;
+            }
+            self.cursor_col = self.cursor_col.saturating_add(1);
+        }
+        Ok(self)
+    }
+
+    /// Fill one row of the region with spaces. The cursor does not move.
+    pub fn clear_row<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        row: u8,
+    ) -> Result<&mut Self, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        if row >= self.height {
+            return Err(Error::RowOutOfRange {
+                row,
+                max: self.height.saturating_sub(1),
+            });
+        }
+        for col in 0..self.width {
+            self.write_cell(lcd, col, row, b' ')?;
+        }
+        Ok(self)
+    }
+
+    /// Fill the region with spaces and move the cursor to the region's top left corner. The rest of the display is
+    /// left untouched.
+    pub fn clear<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<&mut Self, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        for row in 0..self.height {
+            self.clear_row(lcd, row)?;
+        }
+        Ok(self.set_cursor(0, 0))
+    }
+
+    /// Write a character code to a cell of the region, dropping cells that fall outside the display
+    fn write_cell<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        col: u8,
+        row: u8,
+        code: u8,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let col = self.col as u16 + col as u16;
+        let row = self.row as u16 + row as u16;
+        if col >= lcd.cols() as u16 || row >= lcd.rows() as u16 {
+            return Ok(());
+        }
+        lcd.write_cell(col as u8, row as u8, code)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A `core::fmt::Write` handle confined to one row of the display, created with `LcdBackpack::row_writer()`. Text is
+/// written from the start of the row and characters past its end are dropped instead of spilling onto other rows. The
+/// error behind a failed write is kept for `LcdBackpack::take_last_error()`.
+///
+/// ```rust,ignore
+/// write!(lcd.row_writer(1), "T={}", t)?;
+/// ```
+pub struct RowWriter<'a, I2C, D> {
+    lcd: &'a mut LcdBackpack<I2C, D>,
+    row: u8,
+    offset: u8,
+}
+
+impl<'a, I2C, I2C_ERR, D> RowWriter<'a, I2C, D>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    pub(crate) fn new(lcd: &'a mut LcdBackpack<I2C, D>, row: u8) -> Self {
+        Self {
+            lcd,
+            row,
+            offset: 0,
+        }
+    }
+
+    /// Get the number of columns written so far, including characters that were dropped at the end of the row
+    pub fn written(&self) -> u8 {
+        self.offset
+    }
+
+    /// Write a string to the row. Characters past the end of the row are dropped.
+    pub fn print(&mut self, text: &str) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.lcd.check_rows(self.row, 1)?;
+        let start = self.lcd.row_start_col();
+        for c in text.chars() {
+            if self.offset < self.lcd.cols() {
+                let code = self.lcd.single_char_code(c);
+                self.lcd
+                    .write_cell(self.lcd.flow_col(start, self.offset), self.row, code)?;
+            }
+            self.offset = self.offset.saturating_add(1);
+        }
+        Ok(self)
+    }
+
+    /// Fill the rest of the row with spaces, so no characters from previous writes are left behind after a shorter
+    /// text
+    pub fn clear_rest(&mut self) -> Result<&mut Self, Error<I2C_ERR>> {
+        self.lcd.check_rows(self.row, 1)?;
+        let start = self.lcd.row_start_col();
+        while self.offset < self.lcd.cols() {
+            self.lcd
+                .write_cell(self.lcd.flow_col(start, self.offset), self.row, b' ')?;
+            self.offset += 1;
+        }
+        Ok(self)
+    }
+}
+
+/// Implement the `core::fmt::Write` trait for the row writer, allowing it to be used with the `write!` macro.
+impl<I2C, I2C_ERR, D> core::fmt::Write for RowWriter<'_, I2C, D>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
+        if let Err(error) = self.print(s) {
+            self.lcd.last_error = Some(error.without_i2c_error());
+            return Err(core::fmt::Error);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use embedded_hal::blocking::i2c::WriteRead;
+use mcp230xx::Register;
+
+/// First I2C address selectable with the backpack's A0-A2 solder jumpers
+const BACKPACK_ADDRESS_FIRST: u8 = 0x20;
+/// Last I2C address selectable with the backpack's A0-A2 solder jumpers
+const BACKPACK_ADDRESS_LAST: u8 = 0x27;
+
+/// The I2C addresses that responded during a call to [`scan_for_backpack`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScanResult {
+    // bit n is set if address 0x20 + n acknowledged the probe
+    found: u8,
+}
+
+impl ScanResult {
+    /// Returns `true` if no backpack responded
+    pub fn is_empty(&self) -> bool {
+        self.found == 0
+    }
+
+    /// Returns the number of backpacks that responded
+    pub fn count(&self) -> u8 {
+        self.found.count_ones() as u8
+    }
+
+    /// Returns `true` if a backpack responded at the given address
+    pub fn contains(&self, address: u8) -> bool {
+        (BACKPACK_ADDRESS_FIRST..=BACKPACK_ADDRESS_LAST).contains(&address)
+            && self.found & (1 << (address - BACKPACK_ADDRESS_FIRST)) != 0
+    }
+
+    /// Returns the lowest address that responded, if any
+    pub fn first(&self) -> Option<u8> {
+        self.addresses().next()
+    }
+
+    /// Returns an iterator over all addresses that responded, in ascending order
+    pub fn addresses(&self) -> impl Iterator<Item = u8> {
+        let found = self.found;
+        (BACKPACK_ADDRESS_FIRST..=BACKPACK_ADDRESS_LAST)
+            .filter(move |address| found & (1 << (address - BACKPACK_ADDRESS_FIRST)) != 0)
+    }
+}
+
+/// Scan the I2C bus for LCD backpacks. Every address in the 0x20-0x27 range that can be configured with the backpack's
+/// solder jumpers is probed by reading the MCP23008 IODIR register. Addresses that acknowledge the read are reported in
+/// the returned [`ScanResult`]. The I2C bus is borrowed so it can be passed on to the LCD backpack afterwards.
+///
+/// Note that any device in this address range that acknowledges a single register read will be reported, so other
+/// MCP230xx based devices on the same bus will show up as candidates too.
+pub fn scan_for_backpack<I2C, I2C_ERR>(i2c: &mut I2C) -> ScanResult
+where
+    I2C: WriteRead<Error = I2C_ERR>,
+{
+    let mut found = 0;
+    for address in BACKPACK_ADDRESS_FIRST..=BACKPACK_ADDRESS_LAST {
+        let mut data = [0u8];
+        if i2c
+            .write_read(address, &[Register::IODIR.into()], &mut data)
+            .is_ok()
+        {
+            found |= 1 << (address - BACKPACK_ADDRESS_FIRST);
+        }
+    }
+    ScanResult { found }
+}
@@ -0,0 +1,102 @@
+use crate::{MAX_COLS, MAX_ROWS};
+
+const ROWS: usize = MAX_ROWS as usize;
+const COLS: usize = MAX_COLS as usize;
+
+/// A copy of the display contents the driver wrote, used to skip writes of characters the display already shows and
+/// to restore the display after a re-initialization. Only cells written since the last clear are known.
+#[cfg(feature = "shadow")]
+#[derive(Clone, Copy)]
+pub(crate) struct Shadow {
+    codes: [[u8; COLS]; ROWS],
+    known: [u64; ROWS],
+}
+
+#[cfg(feature = "shadow")]
+impl Shadow {
+    pub(crate) const fn new() -> Self {
+        Self {
+            codes: [[b' '; COLS]; ROWS],
+            known: [0; ROWS],
+        }
+    }
+
+    /// Forget every cell, as when the display contents are unknown to the driver
+    pub(crate) fn forget(&mut self) {
+        self.known = [0; ROWS];
+    }
+
+    /// Record that the display was cleared, so every cell holds a space
+    pub(crate) fn cleared(&mut self) {
+        self.codes = [[b' '; COLS]; ROWS];
+        self.known = [u64::MAX; ROWS];
+    }
+
+    /// Check whether a cell is known to hold a character code
+    pub(crate) fn holds(&self, col: u8, row: u8, code: u8) -> bool {
+        let (c, r) = (col as usize, row as usize);
+        c < COLS && r < ROWS && self.known[r] & (1 << c) != 0 && self.codes[r][c] == code
+    }
+
+    /// Record a character code written to a cell
+    pub(crate) fn record(&mut self, col: u8, row: u8, code: u8) {
+        let (c, r) = (col as usize, row as usize);
+        if c < COLS && r < ROWS {
+            self.codes[r][c] = code;
+            self.known[r] |= 1 << c;
+        }
+    }
+
+    /// Get the known cells within `cols` columns and `rows` rows as `(col, row, code)`, row by row
+    pub(crate) fn known_cells(
+        &self,
+        cols: u8,
+        rows: u8,
+    ) -> impl Iterator<Item = (u8, u8, u8)> + '_ {
+        let (cols, rows) = ((cols as usize).min(COLS), (rows as usize).min(ROWS));
+        self.codes
+            .iter()
+            .zip(self.known)
+            .take(rows)
+            .enumerate()
+            .flat_map(move |(row, (codes, known))| {
+                codes
+                    .iter()
+                    .take(cols)
+                    .enumerate()
+                    .filter(move |(col, _)| known & (1 << col) != 0)
+                    .map(move |(col, &code)| (col as u8, row as u8, code))
+            })
+    }
+}
+
+/// Without the `shadow` feature the driver knows nothing about the display contents, so every write goes to the
+/// display and a re-initialization leaves it cleared
+#[cfg(not(feature = "shadow"))]
+#[derive(Clone, Copy)]
+pub(crate) struct Shadow;
+
+#[cfg(not(feature = "shadow"))]
+impl Shadow {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+
+    pub(crate) fn forget(&mut self) {}
+
+    pub(crate) fn cleared(&mut self) {}
+
+    pub(crate) fn holds(&self, _col: u8, _row: u8, _code: u8) -> bool {
+        false
+    }
+
+    pub(crate) fn record(&mut self, _col: u8, _row: u8, _code: u8) {}
+
+    pub(crate) fn known_cells(
+        &self,
+        _cols: u8,
+        _rows: u8,
+    ) -> impl Iterator<Item = (u8, u8, u8)> + '_ {
+        core::iter::empty()
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{number::NumberBuffer, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Glyph index of a blank cell
+const BLANK: u8 = 0xFF;
+
+// glyph indexes of the font glyphs
+const FULL: u8 = 0;
+const TOP: u8 = 1;
+const BOTTOM: u8 = 2;
+const BOTH: u8 = 3;
+const DOT: u8 = 4;
+
+/// Block glyphs both fonts are composed from: a full block, bars at the top, the bottom and both, and a dot
+const GLYPHS: [[u8; 8]; 5] = [
+    [
+        0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b11111, 0b11111, 0b11111, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000,
+    ],
+    [
+        0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b11111, 0b11111, 0b11111, 0b00000, 0b00000, 0b11111, 0b11111, 0b11111,
+    ],
+    [
+        0b00000, 0b00000, 0b01110, 0b01110, 0b01110, 0b00000, 0b00000, 0b00000,
+    ],
+];
+
+/// Cells of the two row digits 0-9, top row first
+const TWO_ROW_DIGITS: [[u8; 6]; 10] = [
+    [FULL, TOP, FULL, FULL, BOTTOM, FULL],
+    [TOP, FULL, BLANK, BOTTOM, FULL, BOTTOM],
+    [BOTH, BOTH, FULL, FULL, BOTTOM, BOTTOM],
+    [BOTH, BOTH, FULL, BOTTOM, BOTTOM, FULL],
+    [FULL, BOTTOM, FULL, BLANK, BLANK, FULL],
+    [FULL, BOTH, BOTH, BOTTOM, BOTTOM, FULL],
+    [FULL, BOTH, BOTH, FULL, BOTTOM, FULL],
+    [TOP, TOP, FULL, BLANK, BLANK, FULL],
+    [FULL, BOTH, FULL, FULL, BOTTOM, FULL],
+    [FULL, BOTH, FULL, BOTTOM, BOTTOM, FULL],
+];
+const TWO_ROW_MINUS: [u8; 6] = [BOTTOM, BOTTOM, BOTTOM, BLANK, BLANK, BLANK];
+const TWO_ROW_SPACE: [u8; 6] = [BLANK; 6];
+const TWO_ROW_COLON: [u8; 2] = [DOT, DOT];
+
+/// Seven segment patterns of the digits 0-9, with segments a-g in bits 0-6
+const SEGMENTS: [u8; 10] = [0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F];
+
+/// Cells of the four row digits 0-9, top row first
+const FOUR_ROW_DIGITS: [[u8; 12]; 10] = {
+    let mut digits = [[BLANK; 12]; 10];
+    let mut digit = 0;
+    while digit < 10 {
+        digits[digit] = four_row_cells(SEGMENTS[digit]);
+        digit += 1;
+    }
+    digits
+};
+const FOUR_ROW_MINUS: [u8; 12] = [
+    BLANK, BLANK, BLANK, BOTTOM, BOTTOM, BOTTOM, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+];
+const FOUR_ROW_SPACE: [u8; 12] = [BLANK; 12];
+const FOUR_ROW_COLON: [u8; 4] = [BLANK, DOT, DOT, BLANK];
+
+/// Get the glyph of a cell a segment runs through, or a blank cell if the segment is off
+const fn bar(on: bool, glyph: u8) -> u8 {
+    if on {
+        glyph
+    } else {
+        BLANK
+    }
+}
+
+/// Get the glyph of a cell on the side of a digit, where a vertical segment fills the whole cell
+const fn side(vertical: bool, horizontal: bool, glyph: u8) -> u8 {
+    if vertical {
+        FULL
+    } else {
+        bar(horizontal, glyph)
+    }
+}
+
+/// Compose the cells of a four row digit from its seven segment pattern. The upper vertical segments fill the top two
+/// rows, the lower ones the bottom two, and the middle segment runs along the bottom of the second row.
+const fn four_row_cells(segments: u8) -> [u8; 12] {
+    let [a, b, c, d, e, f, g] = [
+        segments & 0x01 != 0,
+        segments & 0x02 != 0,
+        segments & 0x04 != 0,
+        segments & 0x08 != 0,
+        segments & 0x10 != 0,
+        segments & 0x20 != 0,
+        segments & 0x40 != 0,
+    ];
+    [
+        side(f, a, TOP),
+        bar(a, TOP),
+        side(b, a, TOP),
+        side(f, g, BOTTOM),
+        bar(g, BOTTOM),
+        side(b, g, BOTTOM),
+        bar(e, FULL),
+        BLANK,
+        bar(c, FULL),
+        side(e, d, BOTTOM),
+        bar(d, BOTTOM),
+        side(c, d, BOTTOM),
+    ]
+}
+
+/// Renders numbers with big digits several rows high, composed from custom block characters, so clocks and counters
+/// can be read from across the room. There is a 2 row font for 16x2 and 20x2 displays and a 4 row font for 20x4
+/// displays. Each digit is 3 columns wide and followed by a blank column. Besides digits, the fonts have a minus sign,
+/// a space and a 1 column wide colon; other characters are skipped. The font's custom characters are loaded into CGRAM
+/// when text is printed.
+///
+/// ```rust,ignore
+/// let big = BigDigits::two_row();
+/// big.print(&mut lcd, 0, 0, "12:45")?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BigDigits {
+    height: u8,
+}
+
+impl BigDigits {
+    /// Create a renderer for the font 2 rows high
+    pub const fn two_row() -> Self {
+        Self { height: 2 }
+    }
+
+    /// Create a renderer for the font 4 rows high
+    pub const fn four_row() -> Self {
+        Self { height: 4 }
+    }
+
+    /// Get the height of the font in rows
+    pub const fn height(&self) -> u8 {
+        self.height
+    }
+
+    /// Print text with big characters, with the top left corner of the first character at the specified column and
+    /// row. Characters that do not fit on the display are dropped. Returns the number of columns written.
+    pub fn print<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        col: u8,
+        row: u8,
+        text: &str,
+    ) -> Result<u8, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        self.print_chars(lcd, col, row, text.chars())
+    }
+
+    /// Print a signed number with big digits. Returns the number of columns written.
+    pub fn print_i32<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        col: u8,
+        row: u8,
+        value: i32,
+    ) -> Result<u8, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let number = NumberBuffer::signed(value);
+        self.print_chars(lcd, col, row, number.as_bytes().iter().map(|&b| b as char))
+    }
+
+    /// Get the glyph indexes of the cells of a character, row by row, or `None` if the font does not have it
+    fn cells(&self, c: char) -> Option<&'static [u8]> {
+        let four_row = self.height == 4;
+        Some(match c {
+            '0'..='9' if four_row => &FOUR_ROW_DIGITS[c as usize - '0' as usize],
+            '0'..='9' => &TWO_ROW_DIGITS[c as usize - '0' as usize],
+            '-' if four_row => &FOUR_ROW_MINUS,
+            '-' => &TWO_ROW_MINUS,
+            ' ' if four_row => &FOUR_ROW_SPACE,
+            ' ' => &TWO_ROW_SPACE,
+            ':' if four_row => &FOUR_ROW_COLON,
+            ':' => &TWO_ROW_COLON,
+            _ => return None,
+        })
+    }
+
+    /// Print characters with big characters, returning the number of columns written
+    fn print_chars<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        col: u8,
+        row: u8,
+        text: impl Iterator<Item = char>,
+    ) -> Result<u8, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(row, self.height)?;
+        let mut locations = [b' '; 8];
+        for (location, glyph) in locations.iter_mut().zip(GLYPHS) {
+            *location = lcd.load_glyph(glyph)?.location();
+        }
+        let mut x = col;
+        for c in text {
+            let Some(cells) = self.cells(c) else {
+                continue;
+            };
+            let width = cells.len() as u8 / self.height;
+            if x as u16 + width as u16 > lcd.cols() as u16 {
+                break;
+            }
+            for (index, &glyph) in cells.iter().enumerate() {
+                let code = match glyph {
+                    BLANK => b' ',
+                    glyph => locations[glyph as usize],
+                };
+                let index = index as u8;
+                lcd.write_cell(x + index % width, row + index / width, code)?;
+            }
+            x += width;
+            // blank column between characters
+            if x < lcd.cols() {
+                for offset in 0..self.height {
+                    lcd.write_cell(x, row + offset, b' ')?;
+                }
+                x += 1;
+            }
+        }
+        Ok(x - col)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{widgets::write_field, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Default time the text is shown, and then hidden, for
+const DEFAULT_HALF_PERIOD_MS: u32 = 500;
+
+/// Text that blinks by itself as time passes. The HD44780 can only blink the cursor, so this alternates the text with
+/// spaces in software. Call `tick()` periodically with the elapsed time, and the text is shown or hidden when the next
+/// phase is due.
+///
+/// ```rust,ignore
+/// let mut alarm = BlinkingText::new("ALARM", 0, 1).half_period_ms(300);
+/// loop {
+///     alarm.tick(&mut lcd, 10)?;
+///     delay.delay_ms(10);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlinkingText<'a> {
+    text: &'a str,
+    col: u8,
+    row: u8,
+    half_period_ms: u32,
+    elapsed_ms: u32,
+    visible: bool,
+    blinking: bool,
+}
+
+impl<'a> BlinkingText<'a> {
+    /// Create blinking text at the specified column and row, shown and hidden for 500 ms each
+    pub const fn new(text: &'a str, col: u8, row: u8) -> Self {
+        Self {
+            text,
+            col,
+            row,
+            half_period_ms: DEFAULT_HALF_PERIOD_MS,
+            elapsed_ms: 0,
+            visible: true,
+            blinking: true,
+        }
+    }
+
+    /// Set the time in milliseconds the text is shown, and then hidden, for
+    pub const fn half_period_ms(mut self, half_period_ms: u32) -> Self {
+        self.half_period_ms = half_period_ms;
+        self
+    }
+
+    /// Replace the text. Call `draw()` to show it right away.
+    pub fn set_text(&mut self, text: &'a str) -> &mut Self {
+        self.text = text;
+        self
+    }
+
+    /// Returns `true` if the text is currently shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Start or stop blinking. Stopped text stays shown. Call `draw()` to show the change right away.
+    pub fn set_blinking(&mut self, blinking: bool) -> &mut Self {
+        self.blinking = blinking;
+        self.elapsed_ms = 0;
+        self.visible = true;
+        self
+    }
+
+    /// Advance the blinking by `elapsed_ms` milliseconds, showing or hiding the text if the next phase is due
+    pub fn tick<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        elapsed_ms: u32,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        if !self.blinking {
+            return Ok(());
+        }
+        self.elapsed_ms = self.elapsed_ms.saturating_add(elapsed_ms);
+        if self.elapsed_ms < self.half_period_ms {
+            return Ok(());
+        }
+        let phases = self.elapsed_ms / self.half_period_ms.max(1);
+        self.elapsed_ms -= phases * self.half_period_ms;
+        if phases % 2 == 1 {
+            self.visible = !self.visible;
+            self.draw(lcd)?;
+        }
+        Ok(())
+    }
+
+    /// Draw the text, or blank its cells while it is hidden
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, 1)?;
+        let width = (self.text.chars().count() as u16).min((lcd.cols() - self.col) as u16) as u8;
+        let text = if self.visible { self.text } else { "" };
+        write_field(lcd, self.col, self.row, width, text)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A clock showing the time as `HH:MM:SS`, or `HH:MM` without seconds. Only the digits that changed since the last
+/// draw are sent, so updating it every second usually costs one or two characters of I2C traffic and does not flicker.
+/// Call `invalidate()` after the LCD was cleared or written to over the clock.
+///
+/// ```rust,ignore
+/// let mut clock = Clock::new(12, 0).blink_colon(true);
+/// // once per second
+/// clock.draw(&mut lcd, hours, minutes, seconds)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clock {
+    col: u8,
+    row: u8,
+    seconds: bool,
+    blink_colon: bool,
+    // characters sent by the last draw, or `None` if the display may not show them
+    drawn: Option<[u8; 8]>,
+}
+
+impl Clock {
+    /// Create a clock with its first digit at the specified column and row
+    pub const fn new(col: u8, row: u8) -> Self {
+        Self {
+            col,
+            row,
+            seconds: true,
+            blink_colon: false,
+            drawn: None,
+        }
+    }
+
+    /// Show or hide the seconds. Shown by default.
+    pub const fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self.drawn = None;
+        self
+    }
+
+    /// Blink the colons, showing them on even seconds and hiding them on odd seconds. Disabled by default.
+    pub const fn blink_colon(mut self, blink_colon: bool) -> Self {
+        self.blink_colon = blink_colon;
+        self
+    }
+
+    /// Send every digit on the next draw, such as after the LCD was cleared or written to over the clock
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.drawn = None;
+        self
+    }
+
+    /// Get the number of columns the clock takes up
+    pub fn width(&self) -> u8 {
+        if self.seconds {
+            8
+        } else {
+            5
+        }
+    }
+
+    /// Show the time. Values too large for two digits are limited to 99.
+    pub fn draw<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, self.width())?;
+        let colon = if self.blink_colon && seconds % 2 == 1 {
+            b' '
+        } else {
+            b':'
+        };
+        let digits = |value: u8| {
+            let value = value.min(99);
+            [b'0' + value / 10, b'0' + value % 10]
+        };
+        let [h1, h2] = digits(hours);
+        let [m1, m2] = digits(minutes);
+        let [s1, s2] = digits(seconds);
+        let codes = [h1, h2, colon, m1, m2, colon, s1, s2];
+        let drawn = self.drawn.take();
+        for (offset, &code) in (0..self.width()).zip(codes.iter()) {
+            if drawn.is_some_and(|drawn| drawn[offset as usize] == code) {
+                continue;
+            }
+            lcd.write_cell(self.col + offset, self.row, code)?;
+        }
+        // a failed write leaves `drawn` unset, so the next draw sends every digit again
+        self.drawn = Some(codes);
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{widgets::write_field, wrap::wrap_line, Error, FrameBuffer, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Text of the options row, with the selection markers in front of Yes and No at `MARKERS`
+const OPTIONS: &[u8] = b" [Yes]  [No]";
+
+/// Offsets of the selection markers of Yes and No in `OPTIONS`
+const MARKERS: [usize; 2] = [0, 7];
+
+/// A navigation input fed to [`ConfirmDialog::handle()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogInput {
+    /// Select Yes
+    Left,
+    /// Select No
+    Right,
+    /// Answer with the selected option
+    Select,
+}
+
+/// The outcome of a [`DialogInput`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogEvent {
+    /// Nothing for the application to act on
+    None,
+    /// Yes was chosen
+    Yes,
+    /// No was chosen
+    No,
+}
+
+/// A modal question answered with Yes or No. The question is word wrapped over the rows above the bottom row, which
+/// shows `[Yes]` and `[No]` with `>` in front of the selected option. The dialog draws over the whole display, and
+/// `close()` brings back the screen underneath from the frame buffer it was rendered into.
+///
+/// ```rust,ignore
+/// let mut dialog = ConfirmDialog::new("Erase all logs?");
+/// dialog.draw(&mut lcd)?;
+/// // for each button press
+/// match dialog.handle(input) {
+///     DialogEvent::Yes => { erase_logs(); dialog.close(&mut lcd, &mut frame)?; }
+///     DialogEvent::No => dialog.close(&mut lcd, &mut frame)?,
+///     DialogEvent::None => dialog.draw(&mut lcd)?,
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfirmDialog<'a> {
+    question: &'a str,
+    yes: bool,
+}
+
+impl<'a> ConfirmDialog<'a> {
+    /// Create a dialog asking `question`, with No selected
+    pub const fn new(question: &'a str) -> Self {
+        Self {
+            question,
+            yes: false,
+        }
+    }
+
+    /// Set whether Yes is selected when the dialog opens
+    pub const fn default_yes(mut self, yes: bool) -> Self {
+        self.yes = yes;
+        self
+    }
+
+    /// Returns `true` if Yes is selected
+    pub fn is_yes_selected(&self) -> bool {
+        self.yes
+    }
+
+    /// Apply a navigation input, returning the answer once an option is chosen. Call `draw()` afterwards to show a
+    /// changed selection.
+    pub fn handle(&mut self, input: DialogInput) -> DialogEvent {
+        match input {
+            DialogInput::Left => self.yes = true,
+            DialogInput::Right => self.yes = false,
+            DialogInput::Select if self.yes => return DialogEvent::Yes,
+            DialogInput::Select => return DialogEvent::No,
+        }
+        DialogEvent::None
+    }
+
+    /// Draw the question and the options over the whole display
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let cols = lcd.cols();
+        let options_row = lcd.rows() - 1;
+        let mut rest = self.question;
+        for row in 0..options_row {
+            let (line, next) = wrap_line(rest, cols as usize);
+            rest = next;
+            write_field(lcd, 0, row, cols, line)?;
+        }
+
+        let mut options = [0; OPTIONS.len()];
+        options.copy_from_slice(OPTIONS);
+        options[MARKERS[if self.yes { 0 } else { 1 }]] = b'>';
+        let start = (cols as usize).saturating_sub(options.len()) / 2;
+        for col in 0..cols {
+            let code = (col as usize)
+                .checked_sub(start)
+                .and_then(|offset| options.get(offset))
+                .copied()
+                .unwrap_or(b' ');
+            lcd.write_cell(col, options_row, code)?;
+        }
+        Ok(())
+    }
+
+    /// Dismiss the dialog by redrawing the whole screen from the frame buffer that holds the contents underneath
+    pub fn close<I2C, I2C_ERR, D, const COLS: usize, const ROWS: usize>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        frame: &mut FrameBuffer<COLS, ROWS>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        frame.invalidate().flush(lcd)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+use heapless::Deque;
+
+/// A scrolling log that keeps the last `LINES` lines of `COLS` characters in a ring buffer and shows the newest of them
+/// on a block of display rows. New lines push older lines up and the oldest line is dropped when the buffer is full.
+/// Lines longer than `COLS` characters wrap onto the next line. `draw()` sends every cell of the console's rows; with the
+/// `shadow` feature only the cells that change are sent.
+///
+/// ```rust,ignore
+/// let mut console: Console<20, 8> = Console::new(0, 4);
+/// console.push_line("Booting");
+/// writeln!(console, "I2C at {:#04x}", address)?;
+/// console.draw(&mut lcd)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Console<const COLS: usize, const LINES: usize> {
+    lines: Deque<[char; COLS], LINES>,
+    // column of the next character on the newest line
+    col: usize,
+    // a newline was written, so the next character starts a new line
+    line_ended: bool,
+    row: u8,
+    rows: u8,
+}
+
+impl<const COLS: usize, const LINES: usize> Console<COLS, LINES> {
+    // evaluated when `new` is instantiated, so a console without columns fails to compile
+    const HAS_COLUMNS: () = assert!(COLS > 0, "Console needs at least one column");
+
+    /// Create an empty console shown on `rows` display rows starting at the specified row. `COLS` must be at least 1,
+    /// which is checked at compile time.
+    pub const fn new(row: u8, rows: u8) -> Self {
+        let () = Self::HAS_COLUMNS;
+        Self {
+            lines: Deque::new(),
+            col: 0,
+            line_ended: false,
+            row,
+            rows,
+        }
+    }
+
+    /// Add a line of text after the last line
+    pub fn push_line(&mut self, text: &str) -> &mut Self {
+        self.new_line();
+        self.print(text);
+        self.line_ended = true;
+        self
+    }
+
+    /// Append text to the last line. A `\n` starts a new line.
+    pub fn print(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            match c {
+                '\n' => {
+                    if self.lines.is_empty() || self.line_ended {
+                        self.new_line();
+                    }
+                    self.line_ended = true;
+                }
+                '\r' => {}
+                _ => self.push_char(c),
+            }
+        }
+        self
+    }
+
+    /// Remove all lines
+    pub fn clear(&mut self) -> &mut Self {
+        self.lines.clear();
+        self.col = 0;
+        self.line_ended = false;
+        self
+    }
+
+    /// Get the number of lines held
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns `true` if the console holds no lines
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Show the newest lines on the console's display rows, oldest at the top. Rows without a line are blank.
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, self.rows)?;
+        let width = COLS.min(lcd.cols() as usize) as u8;
+        let shown = self.lines.len().min(self.rows as usize);
+        let mut lines = self.lines.iter().skip(self.lines.len() - shown);
+        for row in self.row..self.row + self.rows {
+            let line = lines.next();
+            for col in 0..width {
+                let code = line.map_or(b' ', |line| lcd.single_char_code(line[col as usize]));
+                lcd.write_cell(col, row, code)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Store a character on the newest line, starting a new line if needed
+    fn push_char(&mut self, c: char) {
+        if self.lines.is_empty() || self.line_ended || self.col >= COLS {
+            self.new_line();
+        }
+        if let Some(line) = self.lines.back_mut() {
+            line[self.col] = c;
+            self.col += 1;
+        }
+    }
+
+    /// Add a blank line, dropping the oldest line if the buffer is full
+    fn new_line(&mut self) {
+        if self.lines.is_full() {
+            self.lines.pop_front();
+        }
+        let _ = self.lines.push_back([' '; COLS]);
+        self.col = 0;
+        self.line_ended = false;
+    }
+}
+
+/// Implement the `core::fmt::Write` trait for the console, allowing it to be used with the `write!` and `writeln!`
+/// macros.
+impl<const COLS: usize, const LINES: usize> core::fmt::Write for Console<COLS, LINES> {
+    fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
+        self.print(s);
+        Ok(())
+    }
+}
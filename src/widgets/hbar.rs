@@ -0,0 +1,110 @@
+use crate::{glyphs, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Number of steps each character cell of a bar is divided into, one per pixel column
+const STEPS_PER_CELL: u32 = 5;
+
+/// A horizontal bar graph showing several channels at once, one labelled bar per row. Values are scaled from the
+/// graph's `min..=max` range to the bar width, with a resolution of one pixel column using the custom characters in
+/// [`glyphs::PROGRESS`], which are loaded into CGRAM when the graph is drawn.
+///
+/// ```rust,ignore
+/// let graph = HBarGraph::new(0, 100, 12).label_width(4);
+/// graph.draw(&mut lcd, &[("CPU", cpu), ("Fan", fan)])?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HBarGraph {
+    min: i32,
+    max: i32,
+    width: u8,
+    col: u8,
+    row: u8,
+    label_width: u8,
+}
+
+impl HBarGraph {
+    /// Create a bar graph for values from `min` to `max` with bars `width` columns wide. The graph starts at the top left
+    /// of the display, without labels.
+    pub const fn new(min: i32, max: i32, width: u8) -> Self {
+        Self {
+            min,
+            max,
+            width,
+            col: 0,
+            row: 0,
+            label_width: 0,
+        }
+    }
+
+    /// Set the column and row of the graph's top left corner
+    pub const fn position(mut self, col: u8, row: u8) -> Self {
+        self.col = col;
+        self.row = row;
+        self
+    }
+
+    /// Set the number of columns in front of each bar reserved for its label
+    pub const fn label_width(mut self, label_width: u8) -> Self {
+        self.label_width = label_width;
+        self
+    }
+
+    /// Draw a labelled bar for each channel, starting at the graph's row. Channels past the last row are not drawn.
+    pub fn draw<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        channels: &[(&str, i32)],
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let rows = lcd.rows().saturating_sub(self.row);
+        for (index, &(label, value)) in channels.iter().take(rows as usize).enumerate() {
+            self.draw_bar(lcd, index as u8, label, value)?;
+        }
+        Ok(())
+    }
+
+    /// Draw the bar of a single channel, where `index` is the channel's row within the graph
+    pub fn draw_bar<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        index: u8,
+        label: &str,
+        value: i32,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        // positions past the largest u8 saturate, which no display has, so the range checks report them
+        let row = self.row.saturating_add(index);
+        lcd.check_rows(row, 1)?;
+        let bar_col = self.col.saturating_add(self.label_width);
+        lcd.check_cols(bar_col, self.width)?;
+        let blocks = lcd.load_glyphs(glyphs::PROGRESS)?;
+        super::write_field(lcd, self.col, row, self.label_width, label)?;
+
+        let filled = super::scale(
+            value,
+            self.min,
+            self.max,
+            self.width as u32 * STEPS_PER_CELL,
+        );
+        for cell in 0..self.width as u32 {
+            let columns = filled
+                .saturating_sub(cell * STEPS_PER_CELL)
+                .min(STEPS_PER_CELL);
+            let code = match columns {
+                0 => b' ',
+                n => blocks[n as usize - 1].location(),
+            };
+            lcd.write_cell(bar_col + cell as u8, row, code)?;
+        }
+        Ok(())
+    }
+}
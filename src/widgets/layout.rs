@@ -0,0 +1,183 @@
+use crate::{number::NumberBuffer, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// How text is placed in a [`Field`] narrower than the field
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    /// Text starts at the left edge of the field
+    #[default]
+    Left,
+    /// Text ends at the right edge of the field
+    Right,
+}
+
+/// A fixed region of one row that shows a value. Updating the field rewrites only its own cells, truncating text that
+/// is too long and padding text that is too short with spaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Field {
+    col: u8,
+    row: u8,
+    width: u8,
+    align: Align,
+}
+
+impl Field {
+    /// Create a left aligned field `width` columns wide starting at the specified column and row
+    pub const fn new(col: u8, row: u8, width: u8) -> Self {
+        Self {
+            col,
+            row,
+            width,
+            align: Align::Left,
+        }
+    }
+
+    /// Set how text narrower than the field is placed
+    pub const fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Show text in the field
+    pub fn update<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        text: &str,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, self.width)?;
+        let len = text.chars().count().min(self.width as usize) as u8;
+        let start = match self.align {
+            Align::Left => 0,
+            Align::Right => self.width - len,
+        };
+        let mut chars = text.chars();
+        for offset in 0..self.width {
+            let code = match offset.checked_sub(start).filter(|&i| i < len) {
+                Some(_) => chars.next().map_or(b' ', |c| lcd.single_char_code(c)),
+                None => b' ',
+            };
+            lcd.write_cell(self.col + offset, self.row, code)?;
+        }
+        Ok(())
+    }
+
+    /// Show a signed number in the field
+    pub fn update_i32<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        value: i32,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let number = NumberBuffer::signed(value);
+        // the digits are ASCII, so they are always valid UTF-8
+        let text = core::str::from_utf8(number.as_bytes()).unwrap_or_default();
+        self.update(lcd, text)
+    }
+
+    /// Blank the field
+    pub fn clear<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        self.update(lcd, "")
+    }
+}
+
+/// A screen layout of fixed labels and named fields. The labels are drawn once with `draw()`, after which fields are
+/// updated by name without touching the labels or other fields.
+///
+/// ```rust,ignore
+/// const LABELS: [(u8, u8, &str); 2] = [(0, 0, "Temp"), (0, 1, "RH")];
+/// const FIELDS: [(&str, Field); 2] = [
+///     ("temp", Field::new(5, 0, 4).align(Align::Right)),
+///     ("rh", Field::new(5, 1, 4).align(Align::Right)),
+/// ];
+/// let layout = Layout::new(&LABELS, &FIELDS);
+/// layout.draw(&mut lcd)?;
+/// layout.update_i32(&mut lcd, "temp", 21)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Layout<'a> {
+    labels: &'a [(u8, u8, &'a str)],
+    fields: &'a [(&'a str, Field)],
+}
+
+impl<'a> Layout<'a> {
+    /// Create a layout from labels, given as `(col, row, text)`, and named fields
+    pub const fn new(labels: &'a [(u8, u8, &'a str)], fields: &'a [(&'a str, Field)]) -> Self {
+        Self { labels, fields }
+    }
+
+    /// Get a field by name
+    pub fn field(&self, name: &str) -> Option<Field> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .map(|&(_, field)| field)
+    }
+
+    /// Draw the labels and blank the fields
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        for &(col, row, text) in self.labels {
+            let width = text.chars().count().min(u8::MAX as usize) as u8;
+            Field::new(col, row, width).update(lcd, text)?;
+        }
+        for (_, field) in self.fields {
+            field.clear(lcd)?;
+        }
+        Ok(())
+    }
+
+    /// Show text in a field. Returns `false` if the layout has no field with that name.
+    pub fn update<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        name: &str,
+        text: &str,
+    ) -> Result<bool, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        match self.field(name) {
+            Some(field) => field.update(lcd, text).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Show a signed number in a field. Returns `false` if the layout has no field with that name.
+    pub fn update_i32<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        name: &str,
+        value: i32,
+    ) -> Result<bool, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        match self.field(name) {
+            Some(field) => field.update_i32(lcd, value).map(|_| true),
+            None => Ok(false),
+        }
+    }
+}
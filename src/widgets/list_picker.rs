@@ -0,0 +1,130 @@
+use crate::{widgets::write_field, Error, LcdBackpack, MenuInput};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Character code of the scrollbar's thumb, the solid block in the character ROM
+const THUMB: u8 = 0xFF;
+
+/// Character code of the scrollbar's track
+const TRACK: u8 = b'|';
+
+/// The outcome of a [`MenuInput`] fed to [`ListPicker::handle()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListEvent {
+    /// Nothing for the application to act on
+    None,
+    /// The item with this index was chosen
+    Selected(usize),
+    /// Back was pressed
+    Back,
+}
+
+/// A list of items to choose from, shown in a window of rows with `>` in front of the selected item. The window scrolls
+/// to keep the selection in view, and when the list is longer than the window, the last column shows a scrollbar
+/// marking the part of the list in view.
+///
+/// ```rust,ignore
+/// const PROFILES: [&str; 6] = ["Eco", "Comfort", "Boost", "Away", "Night", "Custom"];
+/// let mut list = ListPicker::new(&PROFILES, 0, 4);
+/// list.draw(&mut lcd)?;
+/// if let ListEvent::Selected(index) = list.handle(MenuInput::Select) { ... }
+/// list.draw(&mut lcd)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListPicker<'a> {
+    items: &'a [&'a str],
+    selected: usize,
+    top: usize,
+    row: u8,
+    rows: u8,
+}
+
+impl<'a> ListPicker<'a> {
+    /// Create a list of `items` shown in a window of `rows` rows starting at the specified row
+    pub fn new(items: &'a [&'a str], row: u8, rows: u8) -> Self {
+        Self {
+            items,
+            selected: 0,
+            top: 0,
+            row,
+            rows: rows.max(1),
+        }
+    }
+
+    /// Get the index of the selected item
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select an item, scrolling it into view. Indexes past the end select the last item.
+    pub fn select(&mut self, index: usize) -> &mut Self {
+        self.selected = index.min(self.items.len().saturating_sub(1));
+        self.scroll_to_selection();
+        self
+    }
+
+    /// Apply a navigation input, returning what the application should act on. Call `draw()` afterwards to show the
+    /// result.
+    pub fn handle(&mut self, input: MenuInput) -> ListEvent {
+        match input {
+            MenuInput::Up => self.select(self.selected.saturating_sub(1)),
+            MenuInput::Down => self.select(self.selected + 1),
+            MenuInput::Select if !self.items.is_empty() => {
+                return ListEvent::Selected(self.selected)
+            }
+            MenuInput::Select => return ListEvent::None,
+            MenuInput::Back => return ListEvent::Back,
+        };
+        ListEvent::None
+    }
+
+    /// Draw the visible items across the full width of the display
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, self.rows)?;
+        let cols = lcd.cols();
+        let rows = self.rows as usize;
+        // a single column only has room for the selection indicator
+        let scrollbar = self.items.len() > rows && cols > 1;
+        let label_width = cols.saturating_sub(1 + scrollbar as u8);
+        // thumb size and position in rows, proportional to the part of the list in view
+        let thumb = (rows * rows / self.items.len().max(1)).max(1);
+        let thumb_top = self.top * (rows - thumb) / self.items.len().saturating_sub(rows).max(1);
+        for offset in 0..rows {
+            let row = self.row + offset as u8;
+            let index = self.top + offset;
+            let indicator = if index == self.selected && index < self.items.len() {
+                b'>'
+            } else {
+                b' '
+            };
+            lcd.write_cell(0, row, indicator)?;
+            write_field(
+                lcd,
+                1,
+                row,
+                label_width,
+                self.items.get(index).copied().unwrap_or(""),
+            )?;
+            if scrollbar {
+                let in_thumb = (thumb_top..thumb_top + thumb).contains(&offset);
+                lcd.write_cell(cols - 1, row, if in_thumb { THUMB } else { TRACK })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn scroll_to_selection(&mut self) {
+        let rows = self.rows as usize;
+        if self.selected < self.top {
+            self.top = self.selected;
+        } else if self.selected >= self.top + rows {
+            self.top = self.selected + 1 - rows;
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Error, LcdBackpack};
+use core::iter;
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Default number of blank columns between the end and the start of wrapping text
+const DEFAULT_GAP: u8 = 3;
+
+/// How a [`Marquee`] scrolls text that is wider than its window
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MarqueeMode {
+    /// The text scrolls left continuously, with the start following the end after a gap
+    #[default]
+    Wrap,
+    /// The text scrolls left until its end is in view, then back right until its start is in view
+    Bounce,
+}
+
+/// Scrolls text that is longer than its window through it, one column per step. Call `tick()` periodically and the
+/// marquee advances and redraws itself every `divider` ticks. Text that fits in the window is shown without scrolling.
+///
+/// ```rust,ignore
+/// let mut news = Marquee::new("Sensor 3 offline, check the wiring", 0, 1, 16);
+/// loop {
+///     news.tick(&mut lcd)?;
+///     delay.delay_ms(50);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Marquee<'a> {
+    text: &'a str,
+    len: usize,
+    col: u8,
+    row: u8,
+    width: u8,
+    gap: u8,
+    divider: u8,
+    mode: MarqueeMode,
+    offset: usize,
+    ticks: u8,
+    reverse: bool,
+}
+
+impl<'a> Marquee<'a> {
+    /// Create a marquee showing `text` in a window `width` columns wide, starting at the specified column and row. The
+    /// text wraps around with a 3 column gap and advances on every tick.
+    pub fn new(text: &'a str, col: u8, row: u8, width: u8) -> Self {
+        Self {
+            text,
+            len: text.chars().count(),
+            col,
+            row,
+            width,
+            gap: DEFAULT_GAP,
+            divider: 1,
+            mode: MarqueeMode::Wrap,
+            offset: 0,
+            ticks: 0,
+            reverse: false,
+        }
+    }
+
+    /// Set the number of blank columns between the end and the start of wrapping text
+    pub fn gap(mut self, gap: u8) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the number of ticks per scroll step, slowing the marquee down
+    pub fn divider(mut self, divider: u8) -> Self {
+        self.divider = divider.max(1);
+        self
+    }
+
+    /// Set how the text scrolls
+    pub fn mode(mut self, mode: MarqueeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Replace the text, scrolling back to its start
+    pub fn set_text(&mut self, text: &'a str) -> &mut Self {
+        self.text = text;
+        self.len = text.chars().count();
+        self.offset = 0;
+        self.ticks = 0;
+        self.reverse = false;
+        self
+    }
+
+    /// Count a tick, scrolling the text one column and redrawing it when a step is due
+    pub fn tick<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        self.ticks += 1;
+        if self.ticks < self.divider {
+            return Ok(());
+        }
+        self.ticks = 0;
+        self.step();
+        self.draw(lcd)
+    }
+
+    /// Draw the visible part of the text
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, self.width)?;
+        let blank = iter::repeat(' ');
+        match self.mode {
+            MarqueeMode::Wrap if self.scrolls() => {
+                let spaced = self.text.chars().chain(blank.take(self.gap as usize));
+                self.write(lcd, spaced.cycle().skip(self.offset))
+            }
+            _ => self.write(lcd, self.text.chars().skip(self.offset).chain(blank)),
+        }
+    }
+
+    /// Write the first characters of `visible` across the window
+    fn write<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        visible: impl Iterator<Item = char>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        for (offset, c) in (0..self.width).zip(visible) {
+            let code = lcd.single_char_code(c);
+            lcd.write_cell(self.col + offset, self.row, code)?;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the text is wider than the window and scrolls
+    fn scrolls(&self) -> bool {
+        self.len > self.width as usize
+    }
+
+    /// Move the window one column along the text
+    fn step(&mut self) {
+        if !self.scrolls() {
+            return;
+        }
+        match self.mode {
+            MarqueeMode::Wrap => {
+                self.offset = (self.offset + 1) % (self.len + self.gap as usize);
+            }
+            MarqueeMode::Bounce => {
+                let last = self.len - self.width as usize;
+                if self.reverse {
+                    self.offset = self.offset.saturating_sub(1);
+                    self.reverse = self.offset > 0;
+                } else {
+                    self.offset = (self.offset + 1).min(last);
+                    self.reverse = self.offset == last;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+mod big_digits;
+mod blinking_text;
+mod clock;
+mod confirm_dialog;
+#[cfg(feature = "queue")]
+mod console;
+mod hbar;
+mod layout;
+mod list_picker;
+mod marquee;
+mod sensor_field;
+mod sparkline;
+mod spinner;
+mod splash;
+mod status_line;
+mod table;
+mod typewriter;
+mod value_editor;
+mod vbar;
+
+pub use big_digits::BigDigits;
+pub use blinking_text::BlinkingText;
+pub use clock::Clock;
+pub use confirm_dialog::{ConfirmDialog, DialogEvent, DialogInput};
+#[cfg(feature = "queue")]
+pub use console::Console;
+pub use hbar::HBarGraph;
+pub use layout::{Align, Field, Layout};
+pub use list_picker::{ListEvent, ListPicker};
+pub use marquee::{Marquee, MarqueeMode};
+pub use sensor_field::{SensorField, Trend};
+pub use sparkline::Sparkline;
+pub use spinner::{Spinner, SpinnerStyle};
+pub use splash::Splash;
+pub use status_line::{Segment, StatusLine};
+pub use table::{Column, Table};
+pub use typewriter::Typewriter;
+pub use value_editor::{EditorCursor, EditorEvent, EditorInput, ValueEditor};
+pub use vbar::VBarGraph;
+
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Scale `value` from the range `min..=max` to `0..=steps`, clamping values outside the range
+pub(crate) fn scale(value: i32, min: i32, max: i32, steps: u32) -> u32 {
+    if max <= min {
+        return 0;
+    }
+    let value = value.clamp(min, max);
+    ((value as i64 - min as i64) * steps as i64 / (max as i64 - min as i64)) as u32
+}
+
+/// Write text into a field of `width` columns, truncating it or padding it with spaces
+pub(crate) fn write_field<I2C, I2C_ERR, D>(
+    lcd: &mut LcdBackpack<I2C, D>,
+    col: u8,
+    row: u8,
+    width: u8,
+    text: &str,
+) -> Result<(), Error<I2C_ERR>>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    let mut chars = text.chars();
+    for offset in 0..width {
+        let code = chars.next().map_or(b' ', |c| lcd.single_char_code(c));
+        lcd.write_cell(col + offset, row, code)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::{glyphs, number::NumberBuffer, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// The direction a [`SensorField`]'s value is moving in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Trend {
+    /// The value rose by more than the hysteresis
+    Rising,
+    /// The value fell by more than the hysteresis
+    Falling,
+    /// The value stayed within the hysteresis
+    #[default]
+    Steady,
+}
+
+/// A field showing a sensor reading with its unit and a trend arrow, such as `21.5C↑`. The value is right aligned in
+/// front of the unit and the arrow takes the last column, using the custom characters [`glyphs::ARROW_UP`],
+/// [`glyphs::ARROW_DOWN`] and [`glyphs::ARROW_RIGHT`]. The trend only changes when the value moves away from the last
+/// reference value by more than the hysteresis, so noise around a steady reading does not make the arrow flicker.
+///
+/// ```rust,ignore
+/// let mut temperature = SensorField::new(0, 0, 7, "C").decimals(1).hysteresis(2);
+/// // reading in tenths of a degree
+/// temperature.update(&mut lcd, 215)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SensorField<'a> {
+    col: u8,
+    row: u8,
+    width: u8,
+    unit: &'a str,
+    decimals: u8,
+    hysteresis: i32,
+    reference: Option<i32>,
+    trend: Trend,
+}
+
+impl<'a> SensorField<'a> {
+    /// Create a field `width` columns wide at the specified column and row, showing whole numbers followed by `unit`
+    pub const fn new(col: u8, row: u8, width: u8, unit: &'a str) -> Self {
+        Self {
+            col,
+            row,
+            width,
+            unit,
+            decimals: 0,
+            hysteresis: 0,
+            reference: None,
+            trend: Trend::Steady,
+        }
+    }
+
+    /// Set the number of decimal places of the values passed to `update()`, which are fixed point numbers. For example,
+    /// with 1 decimal place a value of 215 is shown as `21.5`.
+    pub const fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Set how far the value must move from the last reference value, in the same fixed point units as the value, for
+    /// the trend to change
+    pub const fn hysteresis(mut self, hysteresis: i32) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// Get the current trend
+    pub fn trend(&self) -> Trend {
+        self.trend
+    }
+
+    /// Show a new reading, updating the trend arrow. Values too wide for the field lose their leading characters.
+    pub fn update<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        value: i32,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, self.width)?;
+        self.update_trend(value);
+        let arrow = match self.trend {
+            Trend::Rising => glyphs::ARROW_UP,
+            Trend::Falling => glyphs::ARROW_DOWN,
+            Trend::Steady => glyphs::ARROW_RIGHT,
+        };
+        let arrow = lcd.load_glyph(arrow)?.location();
+
+        let number = NumberBuffer::fixed(value, self.decimals);
+        let unit_width = self.unit.chars().count().min(self.width as usize) as u8;
+        let value_width = self.width.saturating_sub(unit_width + 1) as usize;
+        let digits = number.as_bytes();
+        let digits = &digits[digits.len().saturating_sub(value_width)..];
+        let mut col = self.col;
+        for _ in digits.len()..value_width {
+            lcd.write_cell(col, self.row, b' ')?;
+            col += 1;
+        }
+        for &digit in digits {
+            lcd.write_cell(col, self.row, digit)?;
+            col += 1;
+        }
+        for c in self.unit.chars().take(unit_width as usize) {
+            let code = lcd.single_char_code(c);
+            lcd.write_cell(col, self.row, code)?;
+            col += 1;
+        }
+        if col < self.col + self.width {
+            lcd.write_cell(col, self.row, arrow)?;
+        }
+        Ok(())
+    }
+
+    /// Compare a reading with the reference value, moving the reference when the reading leaves the hysteresis band
+    fn update_trend(&mut self, value: i32) {
+        let Some(reference) = self.reference else {
+            self.reference = Some(value);
+            return;
+        };
+        let change = value as i64 - reference as i64;
+        self.trend = if change > self.hysteresis as i64 {
+            Trend::Rising
+        } else if change < -(self.hysteresis as i64) {
+            Trend::Falling
+        } else {
+            Trend::Steady
+        };
+        if self.trend != Trend::Steady {
+            self.reference = Some(value);
+        }
+    }
+}
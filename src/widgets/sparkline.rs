@@ -0,0 +1,107 @@
+use super::vbar::{draw_column, STEPS_PER_CELL};
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A scrolling chart of the last `N` samples, one column per sample, for showing trends such as temperature or signal
+/// strength. New samples appear in the rightmost column and older ones shift left. Samples are scaled from the chart's
+/// `min..=max` range like a [`VBarGraph`](crate::VBarGraph), using the same custom characters.
+///
+/// ```rust,ignore
+/// let mut trend: Sparkline<8> = Sparkline::new(15, 30).position(8, 1);
+/// trend.push(temperature);
+/// trend.draw(&mut lcd)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sparkline<const N: usize> {
+    samples: [i32; N],
+    len: usize,
+    min: i32,
+    max: i32,
+    col: u8,
+    row: u8,
+    height: u8,
+}
+
+impl<const N: usize> Sparkline<N> {
+    /// Create an empty chart for samples from `min` to `max`, one row high at the top left of the display
+    pub const fn new(min: i32, max: i32) -> Self {
+        Self {
+            samples: [0; N],
+            len: 0,
+            min,
+            max,
+            col: 0,
+            row: 0,
+            height: 1,
+        }
+    }
+
+    /// Set the column and row of the chart's top left corner
+    pub const fn position(mut self, col: u8, row: u8) -> Self {
+        self.col = col;
+        self.row = row;
+        self
+    }
+
+    /// Set the height of the chart in rows
+    pub const fn height(mut self, rows: u8) -> Self {
+        self.height = rows;
+        self
+    }
+
+    /// Add a sample, dropping the oldest one once the chart is full
+    pub fn push(&mut self, sample: i32) -> &mut Self {
+        if N == 0 {
+            return self;
+        }
+        if self.len == N {
+            self.samples.copy_within(1.., 0);
+            self.samples[N - 1] = sample;
+        } else {
+            self.samples[self.len] = sample;
+            self.len += 1;
+        }
+        self
+    }
+
+    /// Remove all samples
+    pub fn clear(&mut self) -> &mut Self {
+        self.len = 0;
+        self
+    }
+
+    /// Get the samples in the chart from oldest to newest
+    pub fn samples(&self) -> &[i32] {
+        &self.samples[..self.len]
+    }
+
+    /// Draw the chart. Columns without a sample yet are left blank.
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        // check the whole chart first, so a chart that does not fit is not partly drawn
+        lcd.check_rows(self.row, self.height)?;
+        lcd.check_cols(self.col, N.min(u8::MAX as usize) as u8)?;
+        let steps = self.height as u32 * STEPS_PER_CELL;
+        let blank = N - self.len;
+        for index in 0..N {
+            let filled = match index.checked_sub(blank) {
+                Some(sample) => super::scale(self.samples[sample], self.min, self.max, steps),
+                None => 0,
+            };
+            draw_column(
+                lcd,
+                self.col.saturating_add(index as u8),
+                self.row,
+                self.height,
+                filled,
+            )?;
+        }
+        Ok(())
+    }
+}
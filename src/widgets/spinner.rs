@@ -0,0 +1,167 @@
+use crate::{glyphs, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Default time each frame of a spinner is shown for
+const DEFAULT_FRAME_MS: u32 = 150;
+
+/// Width of the bouncing dot animation in columns
+const BOUNCE_WIDTH: u8 = 3;
+
+/// The animation shown by a [`Spinner`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpinnerStyle {
+    /// A line rotating through `|`, `/`, `-` and `\` in one cell. The backslash is a custom character.
+    Slash,
+    /// A dot bouncing back and forth across 3 cells
+    BouncingDot,
+    /// Custom character frames shown in turn in one cell, loaded into CGRAM as they are drawn
+    Glyphs(&'static [[u8; 8]]),
+}
+
+impl SpinnerStyle {
+    /// Number of frames in the animation
+    fn frames(&self) -> u8 {
+        match self {
+            SpinnerStyle::Slash | SpinnerStyle::BouncingDot => 4,
+            SpinnerStyle::Glyphs(frames) => frames.len().min(u8::MAX as usize) as u8,
+        }
+    }
+}
+
+/// An activity indicator that animates by itself as time passes, placed at any cell. Call `tick()` periodically with
+/// the elapsed time, and the spinner advances and redraws itself when the next frame is due.
+///
+/// ```rust,ignore
+/// let mut busy = Spinner::new(SpinnerStyle::Slash, 15, 0);
+/// loop {
+///     busy.tick(&mut lcd, 10)?;
+///     delay.delay_ms(10);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Spinner {
+    style: SpinnerStyle,
+    col: u8,
+    row: u8,
+    frame: u8,
+    frame_ms: u32,
+    elapsed_ms: u32,
+}
+
+impl Spinner {
+    /// Create a spinner at the specified column and row, showing each frame for 150 ms
+    pub const fn new(style: SpinnerStyle, col: u8, row: u8) -> Self {
+        Self {
+            style,
+            col,
+            row,
+            frame: 0,
+            frame_ms: DEFAULT_FRAME_MS,
+            elapsed_ms: 0,
+        }
+    }
+
+    /// Set the time each frame is shown for in milliseconds
+    pub const fn frame_ms(mut self, frame_ms: u32) -> Self {
+        self.frame_ms = frame_ms;
+        self
+    }
+
+    /// Advance the animation by `elapsed_ms` milliseconds, redrawing the spinner if a new frame is due
+    pub fn tick<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        elapsed_ms: u32,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        self.elapsed_ms = self.elapsed_ms.saturating_add(elapsed_ms);
+        if self.elapsed_ms < self.frame_ms {
+            return Ok(());
+        }
+        let frames = self.style.frames().max(1) as u32;
+        let steps = self.elapsed_ms / self.frame_ms.max(1);
+        self.elapsed_ms -= steps * self.frame_ms;
+        self.frame = ((self.frame as u32 + steps) % frames) as u8;
+        self.draw(lcd)
+    }
+
+    /// Draw the current frame
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        match self.style {
+            SpinnerStyle::Slash => {
+                let code = match self.frame {
+                    0 => b'|',
+                    1 => b'/',
+                    2 => b'-',
+                    _ => lcd.load_glyph(glyphs::BACKSLASH)?.location(),
+                };
+                self.write(lcd, 0, code)
+            }
+            SpinnerStyle::BouncingDot => {
+                // positions 0, 1, 2, 1
+                let position = if self.frame < BOUNCE_WIDTH {
+                    self.frame
+                } else {
+                    2 * (BOUNCE_WIDTH - 1) - self.frame
+                };
+                for offset in 0..BOUNCE_WIDTH {
+                    let code = if offset == position { b'o' } else { b' ' };
+                    self.write(lcd, offset, code)?;
+                }
+                Ok(())
+            }
+            SpinnerStyle::Glyphs(frames) => match frames.get(self.frame as usize) {
+                Some(&glyph) => {
+                    let code = lcd.load_glyph(glyph)?.location();
+                    self.write(lcd, 0, code)
+                }
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Blank the cells the spinner occupies
+    pub fn clear<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let width = match self.style {
+            SpinnerStyle::BouncingDot => BOUNCE_WIDTH,
+            _ => 1,
+        };
+        for offset in 0..width {
+            self.write(lcd, offset, b' ')?;
+        }
+        Ok(())
+    }
+
+    /// Write a character code to a cell of the spinner
+    fn write<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        offset: u8,
+        code: u8,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, offset.saturating_add(1))?;
+        lcd.write_cell(self.col + offset, self.row, code)
+    }
+}
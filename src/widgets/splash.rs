@@ -0,0 +1,106 @@
+use crate::{
+    widgets::{write_field, HBarGraph},
+    Error, LcdBackpack,
+};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A boot screen showing a centered product name and version, with a progress bar across the bottom row. On displays
+/// with two rows, the name and version share the top row. On single row displays, the version is not shown and the
+/// progress bar replaces the name.
+///
+/// ```rust,ignore
+/// let splash = Splash::new("Thermostat", "v1.2.0");
+/// splash.draw(&mut lcd)?;
+/// for (step, percent) in [(init_sensors, 30), (connect, 80), (load_settings, 100)] {
+///     step();
+///     splash.set_progress(&mut lcd, percent)?;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Splash<'a> {
+    name: &'a str,
+    version: &'a str,
+}
+
+impl<'a> Splash<'a> {
+    /// Create a splash screen for a product name and version string
+    pub const fn new(name: &'a str, version: &'a str) -> Self {
+        Self { name, version }
+    }
+
+    /// Draw the name and version, with an empty progress bar
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let rows = lcd.rows();
+        let bar_row = rows - 1;
+        // rows above the bar, with one blank row on top when there is room to spare
+        let name_row = if rows >= 4 { 1 } else { 0 };
+        for row in 0..bar_row {
+            if row == name_row && rows == 2 {
+                let len = self.name.chars().count() + 1 + self.version.chars().count();
+                self.centered(
+                    lcd,
+                    row,
+                    len,
+                    self.name
+                        .chars()
+                        .chain(Some(' '))
+                        .chain(self.version.chars()),
+                )?;
+            } else if row == name_row {
+                self.centered(lcd, row, self.name.chars().count(), self.name.chars())?;
+            } else if row == name_row + 1 {
+                self.centered(lcd, row, self.version.chars().count(), self.version.chars())?;
+            } else {
+                write_field(lcd, 0, row, lcd.cols(), "")?;
+            }
+        }
+        self.set_progress(lcd, 0)
+    }
+
+    /// Show the boot progress in percent on the bottom row. Values over 100 show a full bar.
+    pub fn set_progress<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        percent: u8,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        HBarGraph::new(0, 100, lcd.cols())
+            .position(0, lcd.rows() - 1)
+            .draw_bar(lcd, 0, "", percent as i32)
+    }
+
+    /// Write text of `len` characters centered on a row, blanking the rest of the row
+    fn centered<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        row: u8,
+        len: usize,
+        mut text: impl Iterator<Item = char>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let cols = lcd.cols();
+        let start = (cols as usize).saturating_sub(len) as u8 / 2;
+        for col in 0..cols {
+            let code = if col >= start {
+                text.next().map_or(b' ', |c| lcd.single_char_code(c))
+            } else {
+                b' '
+            };
+            lcd.write_cell(col, row, code)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Error, LcdBackpack, MAX_COLS};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// A segment of a [`StatusLine`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Segment {
+    /// Text aligned to the left edge of the row
+    Left,
+    /// Text centered on the row
+    Center,
+    /// Text aligned to the right edge of the row
+    Right,
+}
+
+/// One row composed of left aligned, centered and right aligned text, such as a mode, a title and a clock. The rest of
+/// the row is padded with spaces. When segments overlap, the segment with the higher priority is shown whole and the
+/// other is cut back so at least one space separates them. By default the left segment has the highest priority,
+/// followed by the right and then the center segment.
+///
+/// ```rust,ignore
+/// let status = StatusLine::new(0);
+/// status.draw(&mut lcd, "AUTO", "Boiler", "12:30")?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatusLine {
+    row: u8,
+    priority: [Segment; 3],
+}
+
+impl StatusLine {
+    /// Create a status line on the specified row
+    pub const fn new(row: u8) -> Self {
+        Self {
+            row,
+            priority: [Segment::Left, Segment::Right, Segment::Center],
+        }
+    }
+
+    /// Set the order in which segments win when they overlap, highest priority first
+    pub const fn priority(mut self, priority: [Segment; 3]) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Draw the status line. With the `shadow` feature, cells the display already shows are skipped, so redrawing it
+    /// with mostly unchanged text, such as once per second for a clock, costs little I2C traffic.
+    pub fn draw<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        left: &str,
+        center: &str,
+        right: &str,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        let cols = (lcd.cols() as usize).min(MAX_COLS as usize);
+        let mut codes = [b' '; MAX_COLS as usize];
+        let mut taken = [false; MAX_COLS as usize];
+        for segment in self.priority {
+            let text = match segment {
+                Segment::Left => left,
+                Segment::Center => center,
+                Segment::Right => right,
+            };
+            let len = text.chars().count().min(cols);
+            let start = match segment {
+                Segment::Left => 0,
+                Segment::Center => (cols - len) / 2,
+                Segment::Right => cols - len,
+            };
+            let placed = taken;
+            // keep a space between this segment and any segment placed before it
+            let free = |col: usize| {
+                !placed[col]
+                    && (col == 0 || !placed[col - 1])
+                    && (col + 1 >= cols || !placed[col + 1])
+            };
+            for (col, c) in (start..start + len).zip(text.chars()) {
+                if free(col) {
+                    codes[col] = lcd.single_char_code(c);
+                    taken[col] = true;
+                }
+            }
+        }
+        for (col, &code) in codes[..cols].iter().enumerate() {
+            lcd.write_cell(col as u8, self.row, code)?;
+        }
+        Ok(())
+    }
+}
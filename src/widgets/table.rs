@@ -0,0 +1,131 @@
+use crate::{
+    widgets::{write_field, Align, Field},
+    Error, LcdBackpack,
+};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// The width and alignment of a [`Table`] column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Column {
+    width: u8,
+    align: Align,
+}
+
+impl Column {
+    /// Create a left aligned column `width` columns wide
+    pub const fn new(width: u8) -> Self {
+        Self {
+            width,
+            align: Align::Left,
+        }
+    }
+
+    /// Set how values narrower than the column are placed
+    pub const fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// Rows of values lined up in columns. The columns are defined once, and each value is then padded or truncated to
+/// its column's width, with a gap of spaces between columns.
+///
+/// ```rust,ignore
+/// const COLUMNS: [Column; 2] = [Column::new(8), Column::new(6).align(Align::Right)];
+/// let table = Table::new(&COLUMNS);
+/// table.draw(&mut lcd, &[&["Inside", "21.5C"], &["Outside", "-3.0C"]])?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Table<'a> {
+    columns: &'a [Column],
+    col: u8,
+    row: u8,
+    gap: u8,
+}
+
+impl<'a> Table<'a> {
+    /// Create a table of `columns` at the top left of the display, with one space between columns
+    pub const fn new(columns: &'a [Column]) -> Self {
+        Self {
+            columns,
+            col: 0,
+            row: 0,
+            gap: 1,
+        }
+    }
+
+    /// Set the column and row of the table's top left corner
+    pub const fn position(mut self, col: u8, row: u8) -> Self {
+        self.col = col;
+        self.row = row;
+        self
+    }
+
+    /// Set the number of spaces between columns
+    pub const fn gap(mut self, gap: u8) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Get the number of display columns the table takes up
+    pub fn width(&self) -> u16 {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column.width as u16)
+            .sum::<u16>();
+        columns + self.gap as u16 * self.columns.len().saturating_sub(1) as u16
+    }
+
+    /// Draw a row of values for each row of the table, starting at the table's row. Rows past the bottom of the
+    /// display are not drawn.
+    pub fn draw<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        rows: &[&[&str]],
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let visible = lcd.rows().saturating_sub(self.row);
+        for (index, values) in rows.iter().take(visible as usize).enumerate() {
+            self.draw_row(lcd, index as u8, values)?;
+        }
+        Ok(())
+    }
+
+    /// Draw one row of values, where `index` is the row within the table. Columns without a value are left blank and
+    /// values without a column are ignored.
+    pub fn draw_row<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        index: u8,
+        values: &[&str],
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        // a row past the largest u8 saturates, which no display has, so the range check reports it
+        let row = self.row.saturating_add(index);
+        lcd.check_rows(row, 1)?;
+        lcd.check_cols(self.col, self.width().min(u8::MAX as u16) as u8)?;
+        let mut col = self.col;
+        for (number, column) in self.columns.iter().enumerate() {
+            if number > 0 {
+                write_field(lcd, col, row, self.gap, "")?;
+                col += self.gap;
+            }
+            let value = values.get(number).copied().unwrap_or("");
+            Field::new(col, row, column.width)
+                .align(column.align)
+                .update(lcd, value)?;
+            col += column.width;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Reveals text one character per tick, as if it were being typed, optionally followed by a cursor character. This
+/// gives menus and boot messages an animated feel without blocking delays in the application. Text that runs past the
+/// end of the row is dropped.
+///
+/// ```rust,ignore
+/// let mut boot = Typewriter::new("Booting...", 0, 0).cursor('_');
+/// while !boot.tick(&mut lcd)? {
+///     delay.delay_ms(80);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Typewriter<'a> {
+    text: &'a str,
+    col: u8,
+    row: u8,
+    cursor: Option<char>,
+    revealed: u8,
+    done: bool,
+}
+
+impl<'a> Typewriter<'a> {
+    /// Create a typewriter for `text` starting at the specified column and row, without a cursor
+    pub fn new(text: &'a str, col: u8, row: u8) -> Self {
+        Self {
+            text,
+            col,
+            row,
+            cursor: None,
+            revealed: 0,
+            done: false,
+        }
+    }
+
+    /// Set a character shown after the last revealed character while typing. Custom characters can be used by passing
+    /// their location as a character, e.g. `'\x01'`.
+    pub fn cursor(mut self, cursor: char) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Returns `true` once the whole text has been revealed
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Start typing the text again from its first character. Characters already on the display are left in place.
+    pub fn restart(&mut self) -> &mut Self {
+        self.revealed = 0;
+        self.done = false;
+        self
+    }
+
+    /// Reveal the next character. Returns `true` once the whole text has been revealed, after which the cursor is
+    /// removed and further ticks do nothing.
+    pub fn tick<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<bool, Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        let room = lcd.cols().saturating_sub(self.col) as usize;
+        let total = self.text.chars().count().min(room);
+        let revealed = self.revealed as usize;
+        if revealed >= total {
+            self.done = true;
+            return Ok(true);
+        }
+        if let Some(c) = self.text.chars().nth(revealed) {
+            let code = lcd.single_char_code(c);
+            lcd.write_cell(self.col + self.revealed, self.row, code)?;
+        }
+        self.revealed += 1;
+        self.done = revealed + 1 == total;
+        if let Some(cursor) = self.cursor {
+            if revealed + 1 < room {
+                // the cursor is erased once typing is done
+                let code = if self.done {
+                    b' '
+                } else {
+                    lcd.single_char_code(cursor)
+                };
+                lcd.write_cell(self.col + self.revealed, self.row, code)?;
+            }
+        }
+        Ok(self.done)
+    }
+
+    /// Reveal the rest of the text at once
+    pub fn finish<I2C, I2C_ERR, D>(
+        &mut self,
+        lcd: &mut LcdBackpack<I2C, D>,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        while !self.tick(lcd)? {}
+        Ok(())
+    }
+}
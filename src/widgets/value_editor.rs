@@ -0,0 +1,222 @@
+use crate::{widgets::write_field, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Most digits a [`ValueEditor`] shows, enough for any `i32`
+const MAX_DIGITS: u8 = 10;
+
+/// An input fed to [`ValueEditor::handle()`], such as from a rotary encoder
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditorInput {
+    /// Increase the active digit, or select the next choice
+    Increment,
+    /// Decrease the active digit, or select the previous choice
+    Decrement,
+    /// Move to the next digit, wrapping around to the first
+    NextDigit,
+    /// Finish editing and keep the new value
+    Commit,
+    /// Finish editing and restore the value editing started with
+    Cancel,
+}
+
+/// The outcome of an [`EditorInput`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditorEvent {
+    /// Nothing for the application to act on
+    None,
+    /// Editing finished with the new value, or the index of the chosen label for a choice editor
+    Committed(i32),
+    /// Editing was cancelled and the value restored
+    Cancelled,
+}
+
+/// How the active digit is marked while editing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EditorCursor {
+    /// Underline the active digit
+    #[default]
+    Underline,
+    /// Blink the active digit
+    Blink,
+}
+
+/// Edits a number digit by digit, or picks one of a list of labels, marking the active digit with the LCD's cursor.
+/// Numbers are shown zero padded to a fixed number of digits, with a sign column if the range allows negative values.
+///
+/// ```rust,ignore
+/// let mut editor = ValueEditor::numeric(10, 1, 3, 0, 500, setpoint);
+/// editor.draw(&mut lcd)?;
+/// // for each encoder event
+/// if let EditorEvent::Committed(value) = editor.handle(EditorInput::Increment) {
+///     setpoint = value;
+/// }
+/// editor.draw(&mut lcd)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueEditor<'a> {
+    col: u8,
+    row: u8,
+    value: i32,
+    initial: i32,
+    min: i32,
+    max: i32,
+    digits: u8,
+    labels: &'a [&'a str],
+    active: u8,
+    editing: bool,
+    cursor: EditorCursor,
+}
+
+impl<'a> ValueEditor<'a> {
+    /// Create an editor for a number of `digits` digits between `min` and `max`, shown at the specified column and row
+    pub fn numeric(col: u8, row: u8, digits: u8, min: i32, max: i32, value: i32) -> Self {
+        let value = value.clamp(min, max.max(min));
+        Self {
+            col,
+            row,
+            value,
+            initial: value,
+            min,
+            max: max.max(min),
+            digits: digits.clamp(1, MAX_DIGITS),
+            labels: &[],
+            active: 0,
+            editing: true,
+            cursor: EditorCursor::Underline,
+        }
+    }
+
+    /// Create an editor that picks one of `labels`, shown at the specified column and row. The value is the index of the
+    /// chosen label.
+    pub fn choice(col: u8, row: u8, labels: &'a [&'a str], index: usize) -> Self {
+        let max = labels.len().saturating_sub(1) as i32;
+        let value = (index as i32).min(max);
+        Self {
+            col,
+            row,
+            value,
+            initial: value,
+            min: 0,
+            max,
+            digits: 1,
+            labels,
+            active: 0,
+            editing: true,
+            cursor: EditorCursor::Underline,
+        }
+    }
+
+    /// Set how the active digit is marked
+    pub const fn cursor(mut self, cursor: EditorCursor) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Get the current value, or the index of the selected label for a choice editor
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Returns `true` until editing is committed or cancelled
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Start editing again from the current value
+    pub fn restart(&mut self) {
+        self.initial = self.value;
+        self.active = 0;
+        self.editing = true;
+    }
+
+    /// Get the number of columns the editor takes up
+    pub fn width(&self) -> u8 {
+        if !self.labels.is_empty() {
+            let widest = self.labels.iter().map(|label| label.chars().count());
+            widest.max().unwrap_or(0).min(u8::MAX as usize) as u8
+        } else if self.min < 0 {
+            self.digits + 1
+        } else {
+            self.digits
+        }
+    }
+
+    /// Process an input, returning what the application should act on. Inputs are ignored once editing finished.
+    pub fn handle(&mut self, input: EditorInput) -> EditorEvent {
+        if !self.editing {
+            return EditorEvent::None;
+        }
+        match input {
+            EditorInput::Increment => self.step(1),
+            EditorInput::Decrement => self.step(-1),
+            EditorInput::NextDigit => {
+                if self.labels.is_empty() {
+                    self.active = (self.active + 1) % self.digits;
+                }
+            }
+            EditorInput::Commit => {
+                self.editing = false;
+                return EditorEvent::Committed(self.value);
+            }
+            EditorInput::Cancel => {
+                self.editing = false;
+                self.value = self.initial;
+                return EditorEvent::Cancelled;
+            }
+        }
+        EditorEvent::None
+    }
+
+    /// Draw the value and, while editing, place the LCD's cursor on the active digit. The cursor is turned off once
+    /// editing finished.
+    pub fn draw<I2C, I2C_ERR, D>(&self, lcd: &mut LcdBackpack<I2C, D>) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        lcd.check_rows(self.row, 1)?;
+        lcd.check_cols(self.col, self.width())?;
+        let cursor_col = if let Some(label) = self.labels.get(self.value as usize) {
+            write_field(lcd, self.col, self.row, self.width(), label)?;
+            self.col
+        } else {
+            let sign = self.min < 0;
+            if sign {
+                let code = if self.value < 0 { b'-' } else { b' ' };
+                lcd.write_cell(self.col, self.row, code)?;
+            }
+            let first = self.col + sign as u8;
+            let mut magnitude = self.value.unsigned_abs();
+            for offset in (0..self.digits).rev() {
+                lcd.write_cell(first + offset, self.row, b'0' + (magnitude % 10) as u8)?;
+                magnitude /= 10;
+            }
+            first + self.active
+        };
+        if self.editing {
+            lcd.set_cursor(cursor_col, self.row)?;
+            lcd.show_cursor(self.cursor == EditorCursor::Underline)?
+                .blink_cursor(self.cursor == EditorCursor::Blink)?;
+        } else {
+            lcd.show_cursor(false)?.blink_cursor(false)?;
+        }
+        Ok(())
+    }
+
+    /// Change the value by one unit of the active digit, or by one choice, staying within the range
+    fn step(&mut self, direction: i64) {
+        let unit = if self.labels.is_empty() {
+            10i64.pow((self.digits - 1 - self.active) as u32)
+        } else {
+            1
+        };
+        // the range may be wider than the digits can show
+        let largest = 10i64.pow(self.digits as u32) - 1;
+        let max = (self.max as i64).min(largest);
+        let min = (self.min as i64).max(-largest);
+        self.value = (self.value as i64 + direction * unit).clamp(min, max.max(min)) as i32;
+    }
+}
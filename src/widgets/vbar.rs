@@ -0,0 +1,125 @@
+use crate::{glyphs, Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Number of steps each character cell of a bar is divided into, one per pixel row
+pub(crate) const STEPS_PER_CELL: u32 = 8;
+
+/// A vertical bar graph for compact meters such as audio levels or fan speeds, with one bar per column. Values are
+/// scaled from the graph's `min..=max` range to the bar height, with a resolution of one pixel row using the 8 custom
+/// characters in [`glyphs::VBAR`], which take up all of CGRAM and are loaded when the graph is drawn. Bars may be
+/// several rows high.
+///
+/// ```rust,ignore
+/// let meter = VBarGraph::new(0, 255).position(12, 0).height(2);
+/// meter.draw(&mut lcd, &[left, right])?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VBarGraph {
+    min: i32,
+    max: i32,
+    col: u8,
+    row: u8,
+    height: u8,
+}
+
+impl VBarGraph {
+    /// Create a bar graph for values from `min` to `max`, with one row high bars starting at the top left of the display
+    pub const fn new(min: i32, max: i32) -> Self {
+        Self {
+            min,
+            max,
+            col: 0,
+            row: 0,
+            height: 1,
+        }
+    }
+
+    /// Set the column of the first bar and the top row of the bars
+    pub const fn position(mut self, col: u8, row: u8) -> Self {
+        self.col = col;
+        self.row = row;
+        self
+    }
+
+    /// Set the height of the bars in rows
+    pub const fn height(mut self, rows: u8) -> Self {
+        self.height = rows;
+        self
+    }
+
+    /// Draw a bar for each value in consecutive columns, starting at the graph's column
+    pub fn draw<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        values: &[i32],
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        for (index, &value) in values.iter().enumerate() {
+            self.draw_bar(lcd, index as u8, value)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a single bar, where `index` is the bar's column within the graph
+    pub fn draw_bar<I2C, I2C_ERR, D>(
+        &self,
+        lcd: &mut LcdBackpack<I2C, D>,
+        index: u8,
+        value: i32,
+    ) -> Result<(), Error<I2C_ERR>>
+    where
+        I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+        D: DelayMs<u16> + DelayUs<u16>,
+    {
+        let filled = super::scale(
+            value,
+            self.min,
+            self.max,
+            self.height as u32 * STEPS_PER_CELL,
+        );
+        // a column past the largest u8 saturates, which no display has, so the range check reports it
+        draw_column(
+            lcd,
+            self.col.saturating_add(index),
+            self.row,
+            self.height,
+            filled,
+        )
+    }
+}
+
+/// Draw a column of vertical bar cells `height` rows high with its top at `row`, filled with `filled` pixel rows from
+/// the bottom
+pub(crate) fn draw_column<I2C, I2C_ERR, D>(
+    lcd: &mut LcdBackpack<I2C, D>,
+    col: u8,
+    row: u8,
+    height: u8,
+    filled: u32,
+) -> Result<(), Error<I2C_ERR>>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    lcd.check_rows(row, height)?;
+    lcd.check_cols(col, 1)?;
+    let blocks = lcd.load_glyphs(glyphs::VBAR)?;
+    for cell in 0..height {
+        // cells are counted from the bottom of the bar
+        let level = filled
+            .saturating_sub(cell as u32 * STEPS_PER_CELL)
+            .min(STEPS_PER_CELL);
+        let code = match level {
+            0 => b' ',
+            n => blocks[n as usize - 1].location(),
+        };
+        lcd.write_cell(col, row + height - 1 - cell, code)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::{Error, LcdBackpack};
+use embedded_hal::{
+    blocking::delay::{DelayMs, DelayUs},
+    blocking::i2c::{Write, WriteRead},
+};
+
+/// Marker written at the end of the last row when wrapped text does not fit on the display
+const ELLIPSIS: &str = "...";
+
+/// Split off the first line of `text` that fits in `cols` columns, returning the line and the remaining text. Lines are
+/// broken at the last space that fits, or mid word if a word is longer than a line, and at every `\n`.
+pub(crate) fn wrap_line(text: &str, cols: usize) -> (&str, &str) {
+    let mut last_space = None;
+    for (count, (i, c)) in text.char_indices().enumerate() {
+        if c == '\n' {
+            return (&text[..i], &text[i + 1..]);
+        }
+        if count == cols {
+            let (end, rest) = match (c, last_space) {
+                (' ', _) => (i, i + 1),
+                (_, Some(space)) => (space, space + 1),
+                (_, None) => (i, i),
+            };
+            return (&text[..end], text[rest..].trim_start_matches(' '));
+        }
+        if c == ' ' {
+            last_space = Some(i);
+        }
+    }
+    (text, "")
+}
+
+impl<I2C, I2C_ERR, D> LcdBackpack<I2C, D>
+where
+    I2C: Write<Error = I2C_ERR> + WriteRead<Error = I2C_ERR>,
+    D: DelayMs<u16> + DelayUs<u16>,
+{
+    /// Enable or disable ending the last row with `...` when text printed with `print_wrapped()` does not fit on the
+    /// display. Disabled by default, in which case the text that does not fit is dropped silently.
+    pub fn set_wrap_ellipsis(&mut self, ellipsis: bool) {
+        self.wrap_ellipsis = ellipsis;
+    }
+
+    /// Fill the display with text, breaking lines between words so each word is shown whole on one row. Words longer
+    /// than a row are broken where the row ends, and `\n` starts a new row. Rows not reached by the text are blanked.
+    /// With the `shadow` feature, characters the display already shows are skipped. Returns `false` if the text did not
+    /// fit on the display.
+    pub fn print_wrapped(&mut self, text: &str) -> Result<bool, Error<I2C_ERR>> {
+        let cols = self.lcd_type.cols() as usize;
+        let rows = self.lcd_type.rows();
+        let start = self.row_start_col();
+        let mut rest = text;
+        for row in 0..rows {
+            let (mut line, next) = wrap_line(rest, cols);
+            rest = next;
+            let ellipsis = self.wrap_ellipsis && row + 1 == rows && !rest.trim().is_empty();
+            if ellipsis {
+                let room = cols.saturating_sub(ELLIPSIS.len());
+                if let Some((end, _)) = line.char_indices().nth(room) {
+                    line = &line[..end];
+                }
+                line = line.trim_end_matches(' ');
+            }
+            let marker = if ellipsis { ELLIPSIS } else { "" };
+            let mut chars = line.chars().chain(marker.chars());
+            for offset in 0..cols as u8 {
+                let code = chars.next().map_or(b' ', |c| self.single_char_code(c));
+                self.write_cell(self.flow_col(start, offset), row, code)?;
+            }
+        }
+        Ok(rest.trim().is_empty())
+    }
+}
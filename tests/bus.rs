@@ -0,0 +1,20 @@
+mod common;
+
+use adafruit_lcd_backpack::{LcdBackpack, LcdDisplayType};
+use common::MockI2c;
+
+#[test]
+fn each_printed_character_is_one_i2c_write() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    // the first character also raises the register select pin
+    assert!(lcd.print("a").is_ok());
+    let writes = bus.borrow().writes;
+    assert!(lcd.print("bcdefgh").is_ok());
+    assert_eq!(bus.borrow().writes - writes, 7);
+    assert_eq!(bus.borrow().ddram(0x00, 8), "abcdefgh");
+}
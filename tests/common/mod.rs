@@ -0,0 +1,210 @@
+//! A simulated MCP23008 driving an HD44780 in 4 bit mode, shared by the integration tests
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use embedded_hal::blocking::{
+    delay::{DelayMs, DelayUs},
+    i2c::{Write, WriteRead},
+};
+
+pub const ADDRESS: u8 = 0x20;
+
+const IOCON: usize = 0x05;
+const GPIO: usize = 0x09;
+const OLAT: usize = 0x0A;
+const REGISTER_COUNT: usize = 11;
+
+const RS_BIT: u8 = 0x02;
+const ENABLE_BIT: u8 = 0x04;
+const SEQOP_BIT: u8 = 0x20;
+
+/// The parts of the HD44780 the driver can observe through the backpack
+pub struct Hd44780 {
+    pub ddram: [u8; 128],
+    pub address_counter: usize,
+    pub four_bit: bool,
+    pub commands: Vec<u8>,
+    high_nibble: Option<u8>,
+    cgram_addressed: bool,
+}
+
+impl Hd44780 {
+    fn new() -> Self {
+        Self {
+            ddram: [b' '; 128],
+            address_counter: 0,
+            four_bit: false,
+            commands: Vec::new(),
+            high_nibble: None,
+            cgram_addressed: false,
+        }
+    }
+
+    fn nibble(&mut self, rs: bool, nibble: u8) {
+        if !self.four_bit {
+            // in 8 bit mode only the function set that switches to 4 bit mode matters
+            if nibble == 0x2 {
+                self.four_bit = true;
+            }
+            return;
+        }
+        match self.high_nibble.take() {
+            None => self.high_nibble = Some(nibble),
+            Some(high) => self.execute(rs, (high << 4) | nibble),
+        }
+    }
+
+    fn execute(&mut self, rs: bool, value: u8) {
+        if rs {
+            if !self.cgram_addressed {
+                self.ddram[self.address_counter] = value;
+                self.address_counter = (self.address_counter + 1) % self.ddram.len();
+            }
+            return;
+        }
+        self.commands.push(value);
+        if value & 0x80 != 0 {
+            self.address_counter = (value & 0x7F) as usize;
+            self.cgram_addressed = false;
+        } else if value & 0x40 != 0 {
+            self.cgram_addressed = true;
+        } else if value & 0x20 != 0 {
+            if value & 0x10 != 0 {
+                self.four_bit = false;
+            }
+        } else if value & 0x10 != 0 {
+            // cursor moves; display shifts leave the address counter alone
+            if value & 0x08 == 0 {
+                let step = if value & 0x04 != 0 { 1 } else { 127 };
+                self.address_counter = (self.address_counter + step) % self.ddram.len();
+            }
+        } else if value == 0x01 {
+            self.ddram = [b' '; 128];
+            self.address_counter = 0;
+            self.cgram_addressed = false;
+        } else if value & 0xFE == 0x02 {
+            self.address_counter = 0;
+            self.cgram_addressed = false;
+        }
+    }
+}
+
+/// Bus state shared between the I2C and delay halves of the simulation
+pub struct Bus {
+    pub registers: [u8; REGISTER_COUNT],
+    pub lcd: Hd44780,
+    pub writes: usize,
+    pub transactions: usize,
+    /// Number of I2C transactions that succeed before every following one fails
+    pub fail_after: Option<usize>,
+    enable_high: bool,
+}
+
+impl Bus {
+    fn transaction(&mut self, address: u8) -> Result<(), BusError> {
+        if address != ADDRESS {
+            return Err(BusError);
+        }
+        self.transactions += 1;
+        match self.fail_after {
+            Some(0) => Err(BusError),
+            Some(remaining) => {
+                self.fail_after = Some(remaining - 1);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn write_register(&mut self, register: usize, value: u8) {
+        if register == GPIO || register == OLAT {
+            self.registers[GPIO] = value;
+            self.registers[OLAT] = value;
+            // the LCD latches the data lines on the falling edge of the enable pin
+            let enable_high = value & ENABLE_BIT != 0;
+            if self.enable_high && !enable_high {
+                self.lcd.nibble(value & RS_BIT != 0, (value >> 3) & 0x0F);
+            }
+            self.enable_high = enable_high;
+        } else {
+            self.registers[register] = value;
+        }
+    }
+
+    /// Get the characters in `len` DDRAM cells starting at `address`
+    pub fn ddram(&self, address: u8, len: usize) -> String {
+        (0..len)
+            .map(|offset| self.lcd.ddram[(address as usize + offset) % 128] as char)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusError;
+
+#[derive(Clone)]
+pub struct MockI2c(pub Rc<RefCell<Bus>>);
+
+impl MockI2c {
+    pub fn new() -> Self {
+        let mut registers = [0; REGISTER_COUNT];
+        // IODIR resets to all inputs
+        registers[0] = 0xFF;
+        Self(Rc::new(RefCell::new(Bus {
+            registers,
+            lcd: Hd44780::new(),
+            writes: 0,
+            transactions: 0,
+            fail_after: None,
+            enable_high: false,
+        })))
+    }
+
+    pub fn delay(&self) -> MockDelay {
+        MockDelay
+    }
+}
+
+impl Write for MockI2c {
+    type Error = BusError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), BusError> {
+        let mut bus = self.0.borrow_mut();
+        bus.transaction(address)?;
+        bus.writes += 1;
+        let mut register = bytes[0] as usize % REGISTER_COUNT;
+        let sequential = bus.registers[IOCON] & SEQOP_BIT == 0;
+        for &value in &bytes[1..] {
+            bus.write_register(register, value);
+            if sequential {
+                register = (register + 1) % REGISTER_COUNT;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WriteRead for MockI2c {
+    type Error = BusError;
+
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), BusError> {
+        let mut bus = self.0.borrow_mut();
+        bus.transaction(address)?;
+        let register = bytes[0] as usize;
+        for (offset, value) in buffer.iter_mut().enumerate() {
+            *value = bus.registers[(register + offset) % REGISTER_COUNT];
+        }
+        Ok(())
+    }
+}
+
+pub struct MockDelay;
+
+impl DelayMs<u16> for MockDelay {
+    fn delay_ms(&mut self, _ms: u16) {}
+}
+
+impl DelayUs<u16> for MockDelay {
+    fn delay_us(&mut self, _us: u16) {}
+}
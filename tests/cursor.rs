@@ -0,0 +1,47 @@
+mod common;
+
+use adafruit_lcd_backpack::{LcdBackpack, LcdDisplayType};
+use common::MockI2c;
+
+#[test]
+fn cursor_moves_stop_at_the_edges_of_the_row() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    assert!(lcd
+        .set_cursor(0, 1)
+        .and_then(|lcd| lcd.move_cursor_left())
+        .is_ok());
+    assert_eq!(lcd.get_cursor(), (0, 1));
+    assert_eq!(bus.borrow().lcd.address_counter, 0x40);
+
+    assert!(lcd
+        .set_cursor(15, 1)
+        .and_then(|lcd| lcd.move_cursor_right())
+        .is_ok());
+    assert_eq!(lcd.get_cursor(), (15, 1));
+    assert_eq!(bus.borrow().lcd.address_counter, 0x4F);
+
+    assert!(lcd.move_cursor_left().is_ok());
+    assert_eq!(lcd.get_cursor(), (14, 1));
+    assert_eq!(bus.borrow().lcd.address_counter, 0x4E);
+}
+
+#[test]
+fn cursor_past_the_end_of_the_row_moves_left_onto_the_row() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    // unchecked text runs on past the end of the row
+    assert!(lcd.print("0123456789abcdefgh").is_ok());
+    assert_eq!(lcd.get_cursor(), (18, 0));
+    assert!(lcd.move_cursor_left().is_ok());
+    assert_eq!(lcd.get_cursor(), (15, 0));
+    assert_eq!(bus.borrow().lcd.address_counter, 0x0F);
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use core::fmt::Write as _;
+
+use adafruit_lcd_backpack::{Error, LcdBackpack, LcdDisplayType, OverflowPolicy};
+use common::{BusError, MockI2c};
+
+#[test]
+fn failed_write_keeps_the_kind_of_error() {
+    let i2c = MockI2c::new();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+    lcd.set_overflow_policy(OverflowPolicy::Error);
+
+    assert!(write!(lcd, "{:>20}", 1).is_err());
+    assert!(matches!(
+        lcd.take_last_error(),
+        Some(Error::ColumnOutOfRange { .. })
+    ));
+    assert!(lcd.take_last_error().is_none());
+}
+
+#[test]
+fn print_fmt_returns_the_i2c_error() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    bus.borrow_mut().fail_after = Some(0);
+    let result = lcd.print_fmt(format_args!("T={}", 21));
+    assert!(matches!(result, Err(Error::I2cError(BusError))));
+    assert!(write!(lcd, "T={}", 21).is_err());
+    assert!(matches!(lcd.take_last_error(), Some(Error::I2cError(()))));
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use adafruit_lcd_backpack::{FrameBuffer, LcdBackpack, LcdDisplayType};
+use common::MockI2c;
+
+#[test]
+fn failed_flush_redraws_the_view_on_the_next_flush() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd20x4, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    let mut fb: FrameBuffer<40, 1> = FrameBuffer::new();
+    fb.print("aaaaaaaaaaaaaaaaaaaabbbbbbbbbbbbbbbbbbbb");
+    assert!(fb.pan_to(20, 0).flush(&mut lcd).is_ok());
+    assert!(fb.pan_to(0, 0).flush(&mut lcd).is_ok());
+    assert_eq!(bus.borrow().ddram(0x00, 20), "aaaaaaaaaaaaaaaaaaaa");
+
+    // the bus fails part of the way through drawing the panned view
+    fb.pan_to(20, 0);
+    bus.borrow_mut().fail_after = Some(4);
+    assert!(fb.flush(&mut lcd).is_err());
+    bus.borrow_mut().fail_after = None;
+
+    assert!(fb.flush(&mut lcd).is_ok());
+    assert_eq!(bus.borrow().ddram(0x00, 20), "bbbbbbbbbbbbbbbbbbbb");
+}
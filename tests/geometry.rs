@@ -0,0 +1,87 @@
+mod common;
+
+use adafruit_lcd_backpack::{Error, LcdBackpack, LcdDisplayType};
+use common::MockI2c;
+
+const OFFSETS: [u8; 4] = [0x00, 0x40, 0x14, 0x54];
+
+#[test]
+fn zero_row_custom_display_is_rejected() {
+    let i2c = MockI2c::new();
+    let delay = i2c.delay();
+    let lcd_type = LcdDisplayType::Custom {
+        cols: 16,
+        rows: 0,
+        offsets: OFFSETS,
+    };
+    let result = LcdBackpack::try_new(lcd_type, i2c, delay);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidGeometry { rows: 0, cols: 16 })
+    ));
+}
+
+#[test]
+fn zero_column_and_too_wide_custom_displays_are_rejected() {
+    for cols in [0, 41] {
+        let i2c = MockI2c::new();
+        let delay = i2c.delay();
+        let lcd_type = LcdDisplayType::Custom {
+            cols,
+            rows: 2,
+            offsets: OFFSETS,
+        };
+        let result = LcdBackpack::try_new(lcd_type, i2c, delay);
+        assert!(matches!(result, Err(Error::InvalidGeometry { rows: 2, cols: c }) if c == cols));
+    }
+}
+
+#[test]
+fn largest_custom_display_is_accepted() {
+    let i2c = MockI2c::new();
+    let delay = i2c.delay();
+    let lcd_type = LcdDisplayType::Custom {
+        cols: 40,
+        rows: 4,
+        offsets: OFFSETS,
+    };
+    assert!(LcdBackpack::try_new(lcd_type, i2c, delay).is_ok());
+}
+
+#[test]
+fn zero_geometry_from_new_is_clamped() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let lcd_type = LcdDisplayType::Custom {
+        cols: 0,
+        rows: 0,
+        offsets: OFFSETS,
+    };
+    let mut lcd = LcdBackpack::new(lcd_type, i2c, delay);
+    assert!(lcd.init().is_ok());
+    assert!(lcd.set_cursor(0, 0).is_ok());
+    assert!(matches!(
+        lcd.set_cursor(0, 1),
+        Err(Error::RowOutOfRange { row: 1, max: 0 })
+    ));
+    assert!(lcd.print("x").is_ok());
+    assert!(lcd.clear_row(0).is_ok());
+    assert_eq!(bus.borrow().ddram(0x00, 1), " ");
+}
+
+#[test]
+fn clear_row_past_the_last_row_is_out_of_range() {
+    let i2c = MockI2c::new();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+    assert!(matches!(
+        lcd.clear_row(2),
+        Err(Error::RowOutOfRange { row: 2, max: 1 })
+    ));
+    assert!(matches!(
+        lcd.clear_row(u8::MAX),
+        Err(Error::RowOutOfRange { row: 255, max: 1 })
+    ));
+}
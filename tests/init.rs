@@ -0,0 +1,67 @@
+mod common;
+
+use adafruit_lcd_backpack::{Error, InitError, LcdBackpack, LcdDisplayType};
+use common::MockI2c;
+
+/// Count the I2C transactions of a successful `init()`
+fn init_transactions() -> usize {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+    let transactions = bus.borrow().transactions;
+    transactions
+}
+
+#[test]
+fn reset_before_init_is_rejected_without_bus_traffic() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(matches!(lcd.reset(), Err(Error::NotInitialized)));
+    assert_eq!(bus.borrow().transactions, 0);
+}
+
+#[test]
+fn failed_clear_leaves_the_lcd_uninitialized() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+
+    // clearing is the last step of init, so failing the last transaction fails it
+    bus.borrow_mut().fail_after = Some(init_transactions() - 1);
+    assert!(matches!(lcd.init(), Err(InitError::Clear(_))));
+    bus.borrow_mut().fail_after = None;
+
+    assert!(matches!(lcd.print("x"), Err(Error::NotInitialized)));
+    assert!(matches!(lcd.reset(), Err(Error::NotInitialized)));
+}
+
+#[test]
+fn failures_are_reported_with_the_step_that_failed() {
+    let total = init_transactions();
+    let mut steps = Vec::new();
+    for fail_after in 0..total {
+        let i2c = MockI2c::new();
+        let bus = i2c.0.clone();
+        let delay = i2c.delay();
+        let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+        bus.borrow_mut().fail_after = Some(fail_after);
+        let step = match lcd.init() {
+            Err(InitError::PendingUpdate(_)) => "pending update",
+            Err(InitError::ExpanderSetup(_)) => "expander setup",
+            Err(InitError::PowerOnDelay(_)) => "power on delay",
+            Err(InitError::FourBitMode(_)) => "4 bit mode",
+            Err(InitError::Clear(_)) => "clear",
+            Ok(_) => "none",
+        };
+        if steps.last() != Some(&step) {
+            steps.push(step);
+        }
+    }
+    // the power on delay has nothing to send, so a bus failure after the expander setup is 4 bit mode's
+    assert_eq!(steps, ["expander setup", "4 bit mode", "clear"]);
+}
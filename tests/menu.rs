@@ -0,0 +1,62 @@
+mod common;
+
+use adafruit_lcd_backpack::{LcdBackpack, LcdDisplayType, Menu, MenuInput, MenuItem};
+use common::MockI2c;
+
+#[test]
+fn menu_draws_on_a_single_column_display() {
+    const CHILDREN: [MenuItem; 1] = [MenuItem::Action {
+        label: "Child",
+        id: 0,
+    }];
+    const ROOT: [MenuItem; 3] = [
+        MenuItem::Submenu {
+            label: "Settings",
+            items: &CHILDREN,
+        },
+        MenuItem::Action {
+            label: "Reboot",
+            id: 1,
+        },
+        MenuItem::Value {
+            label: "Contrast",
+            id: 0,
+            min: 0,
+            max: 10,
+            step: 1,
+        },
+    ];
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let lcd_type = LcdDisplayType::Custom {
+        cols: 1,
+        rows: 2,
+        offsets: [0x00, 0x40, 0x00, 0x40],
+    };
+    let mut lcd = LcdBackpack::new(lcd_type, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    let mut menu: Menu<1> = Menu::new(&ROOT, 2);
+    assert!(menu.draw(&mut lcd).is_ok());
+    assert_eq!(bus.borrow().ddram(0x00, 1), ">");
+    menu.handle(MenuInput::Down);
+    menu.handle(MenuInput::Down);
+    assert!(menu.draw(&mut lcd).is_ok());
+    assert_eq!(bus.borrow().ddram(0x40, 1), ">");
+}
+
+#[test]
+fn menu_selects_items_past_255() {
+    let items: Vec<MenuItem> = (0..300)
+        .map(|_| MenuItem::Action {
+            label: "Item",
+            id: 0,
+        })
+        .collect();
+    let mut menu: Menu = Menu::new(&items, 2);
+    for _ in 0..400 {
+        menu.handle(MenuInput::Down);
+    }
+    assert!(core::ptr::eq(menu.selected().unwrap(), &items[299]));
+}
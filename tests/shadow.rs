@@ -0,0 +1,21 @@
+#![cfg(feature = "shadow")]
+
+mod common;
+
+use adafruit_lcd_backpack::{LcdBackpack, LcdDisplayType};
+use common::MockI2c;
+
+#[test]
+fn unchanged_row_is_not_written_again() {
+    let i2c = MockI2c::new();
+    let bus = i2c.0.clone();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(LcdDisplayType::Lcd16x2, i2c, delay);
+    assert!(lcd.init().is_ok());
+
+    assert!(lcd.write_row(1, "unchanged").is_ok());
+    let writes = bus.borrow().writes;
+    assert!(lcd.write_row(1, "unchanged").is_ok());
+    assert_eq!(bus.borrow().writes, writes);
+    assert_eq!(bus.borrow().ddram(0x40, 16), "unchanged       ");
+}
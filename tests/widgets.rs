@@ -0,0 +1,116 @@
+mod common;
+
+use adafruit_lcd_backpack::{
+    Clock, Column, Error, HBarGraph, LcdBackpack, LcdDisplayType, ListPicker, Sparkline, Table,
+    VBarGraph,
+};
+use common::{MockDelay, MockI2c};
+
+/// Create an initialized backpack on a simulated bus
+fn lcd(lcd_type: LcdDisplayType) -> (MockI2c, LcdBackpack<MockI2c, MockDelay>) {
+    let i2c = MockI2c::new();
+    let delay = i2c.delay();
+    let mut lcd = LcdBackpack::new(lcd_type, i2c.clone(), delay);
+    assert!(lcd.init().is_ok());
+    (i2c, lcd)
+}
+
+#[test]
+fn list_picker_draws_on_a_single_column_display() {
+    let (i2c, mut lcd) = lcd(LcdDisplayType::Custom {
+        cols: 1,
+        rows: 2,
+        offsets: [0x00, 0x40, 0x00, 0x40],
+    });
+    let picker = ListPicker::new(&["one", "two", "three"], 0, 2);
+    assert!(picker.draw(&mut lcd).is_ok());
+    assert_eq!(i2c.0.borrow().ddram(0x00, 1), ">");
+    assert_eq!(i2c.0.borrow().ddram(0x40, 1), " ");
+}
+
+#[test]
+fn hbar_positions_past_u8_are_out_of_range() {
+    let (_i2c, mut lcd) = lcd(LcdDisplayType::Lcd20x4);
+    let graph = HBarGraph::new(0, 100, 4).position(0, 200);
+    let result = graph.draw_bar(&mut lcd, 100, "x", 50);
+    assert!(matches!(
+        result,
+        Err(Error::RowOutOfRange { row: 255, max: 3 })
+    ));
+    let graph = HBarGraph::new(0, 100, 4).position(200, 0).label_width(100);
+    let result = graph.draw_bar(&mut lcd, 0, "x", 50);
+    assert!(matches!(
+        result,
+        Err(Error::ColumnOutOfRange { col: 255, max: 19 })
+    ));
+}
+
+#[test]
+fn vbar_columns_past_u8_are_out_of_range() {
+    let (_i2c, mut lcd) = lcd(LcdDisplayType::Lcd20x4);
+    let graph = VBarGraph::new(0, 100).position(200, 0);
+    let result = graph.draw_bar(&mut lcd, 100, 50);
+    assert!(matches!(
+        result,
+        Err(Error::ColumnOutOfRange { col: 255, max: 19 })
+    ));
+}
+
+#[test]
+fn sparkline_columns_past_u8_are_out_of_range() {
+    let (i2c, mut lcd) = lcd(LcdDisplayType::Lcd20x4);
+    let mut chart: Sparkline<8> = Sparkline::new(0, 100).position(250, 0);
+    chart.push(50);
+    let writes = i2c.0.borrow().writes;
+    let result = chart.draw(&mut lcd);
+    assert!(matches!(
+        result,
+        Err(Error::ColumnOutOfRange { col: 255, max: 19 })
+    ));
+    assert_eq!(i2c.0.borrow().writes, writes);
+
+    // a chart running off the right edge is rejected before any column is drawn
+    let chart: Sparkline<8> = Sparkline::new(0, 100).position(16, 0);
+    let result = chart.draw(&mut lcd);
+    assert!(matches!(
+        result,
+        Err(Error::ColumnOutOfRange { col: 23, max: 19 })
+    ));
+    assert_eq!(i2c.0.borrow().writes, writes);
+}
+
+#[test]
+fn table_rows_past_u8_are_out_of_range() {
+    let (_i2c, mut lcd) = lcd(LcdDisplayType::Lcd20x4);
+    let columns = [Column::new(4), Column::new(4)];
+    let table = Table::new(&columns).position(0, 200);
+    let result = table.draw_row(&mut lcd, 100, &["a", "b"]);
+    assert!(matches!(
+        result,
+        Err(Error::RowOutOfRange { row: 255, max: 3 })
+    ));
+}
+
+#[test]
+fn clock_sends_only_the_digits_that_changed() {
+    let (i2c, mut lcd) = lcd(LcdDisplayType::Lcd16x2);
+    let mut clock = Clock::new(0, 0);
+    assert!(clock.draw(&mut lcd, 12, 34, 56).is_ok());
+    assert_eq!(i2c.0.borrow().ddram(0x00, 8), "12:34:56");
+
+    let writes = i2c.0.borrow().writes;
+    assert!(clock.draw(&mut lcd, 12, 34, 56).is_ok());
+    assert_eq!(i2c.0.borrow().writes, writes);
+
+    // the same traffic as moving the cursor to the last digit and printing it
+    assert!(lcd.set_cursor(7, 0).and_then(|lcd| lcd.print("6")).is_ok());
+    let one_digit = i2c.0.borrow().writes - writes;
+    let writes = i2c.0.borrow().writes;
+    assert!(clock.draw(&mut lcd, 12, 34, 57).is_ok());
+    assert_eq!(i2c.0.borrow().writes, writes + one_digit);
+    assert_eq!(i2c.0.borrow().ddram(0x00, 8), "12:34:57");
+
+    assert!(lcd.clear().is_ok());
+    assert!(clock.invalidate().draw(&mut lcd, 12, 34, 57).is_ok());
+    assert_eq!(i2c.0.borrow().ddram(0x00, 8), "12:34:57");
+}